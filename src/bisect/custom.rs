@@ -0,0 +1,80 @@
+use crate::optionarg;
+use crate::wrap_command::FnOptionArg;
+
+pub const START: &str = "start";
+pub const BAD: &str = "bad";
+pub const NEW: &str = "new";
+pub const GOOD: &str = "good";
+pub const OLD: &str = "old";
+pub const SKIP: &str = "skip";
+pub const RESET: &str = "reset";
+pub const LOG: &str = "log";
+pub const REPLAY: &str = "replay";
+pub const RUN: &str = "run";
+pub const TERMS: &str = "terms";
+
+/// Start a bisection, optionally giving the bad revision followed by the good ones.
+/// start
+pub fn start() -> FnOptionArg {
+    optionarg::simple(START)
+}
+
+/// Mark the current revision, or the given ones, as bad.
+/// bad
+pub fn bad() -> FnOptionArg {
+    optionarg::simple(BAD)
+}
+
+/// Mark the current revision, or the given ones, as new (synonym of bad).
+/// new
+pub fn new() -> FnOptionArg {
+    optionarg::simple(NEW)
+}
+
+/// Mark the current revision, or the given ones, as good.
+/// good
+pub fn good() -> FnOptionArg {
+    optionarg::simple(GOOD)
+}
+
+/// Mark the current revision, or the given ones, as old (synonym of good).
+/// old
+pub fn old() -> FnOptionArg {
+    optionarg::simple(OLD)
+}
+
+/// Skip the current revision, or the given ones and ranges, as they cannot be tested.
+/// skip
+pub fn skip() -> FnOptionArg {
+    optionarg::simple(SKIP)
+}
+
+/// Clean up the bisection state and return to the original HEAD, or to the given commit.
+/// reset
+pub fn reset() -> FnOptionArg {
+    optionarg::simple(RESET)
+}
+
+/// Show what has been done so far in the bisection.
+/// log
+pub fn log() -> FnOptionArg {
+    optionarg::simple(LOG)
+}
+
+/// Replay a bisection from a log saved with bisect log.
+/// replay
+pub fn replay() -> FnOptionArg {
+    optionarg::simple(REPLAY)
+}
+
+/// Bisect automatically, using the exit code of the given command to tell good revisions from bad ones.
+/// run
+pub fn run() -> FnOptionArg {
+    optionarg::simple(RUN)
+}
+
+/// Show the terms used for old and new commits.
+/// terms
+pub fn terms() -> FnOptionArg {
+    optionarg::simple(TERMS)
+}
@@ -0,0 +1,98 @@
+use std::sync::OnceLock;
+use regex::Regex;
+use crate::bisect::{self, bisect};
+use crate::parser::parse_error;
+use crate::wrap_command::WrapCommand;
+use crate::{optionarg, rev_parse, WrapError};
+
+const EXP_FIRST_BAD: &str = r"(?m)^([0-9a-f]{40,64}) is the first bad commit";
+
+/// Outcome of testing a revision during a bisection
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BisectVerdict {
+    Good,
+    Bad,
+    /// The revision cannot be tested
+    Skip,
+}
+
+/// Result of a bisection, see [find_first_bad]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BisectOutcome {
+    /// Object name of the first bad commit
+    pub first_bad: String,
+    /// Output of git bisect log, which git bisect replay accepts
+    pub log: String,
+}
+
+/// Bisects the history between the [bad] revision and the [good] ones, calling [test] with the object name
+/// of every revision checked out until git finds the first bad commit.
+/// The bisection is reset before returning, whatever the outcome.
+/// At least one good revision is required: git cannot bisect from a bad one alone.
+pub fn find_first_bad<F>(current_dir: Option<&str>, bad: &str, good: &[&str], mut test: F) -> Result<BisectOutcome, WrapError>
+where
+    F: FnMut(&str) -> BisectVerdict,
+{
+    if good.is_empty() {
+        return Err(WrapError::InvalidArgument(String::from("bisection needs at least one good revision")));
+    }
+    let bad = resolve(current_dir, bad)?;
+    let good = good.iter().map(|g| resolve(current_dir, g)).collect::<Result<Vec<_>, _>>()?;
+
+    let mut cmd = bisect(current_dir);
+    cmd.option(bisect::start());
+    cmd.option(bisect::rev(bad.as_str()));
+    for g in &good {
+        cmd.option(bisect::rev(g.as_str()));
+    }
+    let result = walk(current_dir, &cmd, &mut test).and_then(|first_bad| {
+        let mut cmd = bisect(current_dir);
+        cmd.option(bisect::log());
+        let log = cmd.execute_output()?.stdout;
+        Ok(BisectOutcome { first_bad, log })
+    });
+
+    let mut cmd = bisect(current_dir);
+    cmd.option(bisect::reset());
+    let reset = cmd.execute_output();
+    let outcome = result?;
+    reset?;
+    Ok(outcome)
+}
+
+fn walk<F>(current_dir: Option<&str>, start: &WrapCommand, test: &mut F) -> Result<String, WrapError>
+where
+    F: FnMut(&str) -> BisectVerdict,
+{
+    static RE: OnceLock<Regex> = OnceLock::new();
+    let first_bad = RE.get_or_init(|| Regex::new(EXP_FIRST_BAD).unwrap());
+
+    let mut output = start.execute_output()?.stdout;
+    let mut tested: Option<String> = None;
+    loop {
+        if let Some(caps) = first_bad.captures(output.as_str()) {
+            return Ok(String::from(&caps[1]));
+        }
+        let revision = resolve(current_dir, "HEAD")?;
+        // git checks out another revision after every verdict until it finds the first bad commit
+        if tested.as_deref() == Some(revision.as_str()) {
+            return Err(parse_error("bisect step", output.as_str()));
+        }
+        let mut cmd = bisect(current_dir);
+        cmd.option(match test(revision.as_str()) {
+            BisectVerdict::Good => bisect::good(),
+            BisectVerdict::Bad => bisect::bad(),
+            BisectVerdict::Skip => bisect::skip(),
+        });
+        output = cmd.execute_output()?.stdout;
+        tested = Some(revision);
+    }
+}
+
+// full object name of the commit [revision] points to
+fn resolve(current_dir: Option<&str>, revision: &str) -> Result<String, WrapError> {
+    let mut cmd = rev_parse::rev_parse(current_dir);
+    cmd.option(rev_parse::verify());
+    cmd.option(optionarg::value_parameter(format!("{revision}^{{commit}}").as_str()));
+    Ok(String::from(cmd.execute_output()?.stdout.trim()))
+}
@@ -0,0 +1,20 @@
+use crate::wrap_command::WrapCommand;
+use crate::git;
+
+mod options;
+pub use options::*;
+
+mod custom;
+pub use custom::*;
+
+mod driver;
+pub use driver::*;
+
+pub const GIT_COMMAND: &str = "bisect";
+
+/// Use binary search to find the commit that introduced a bug.
+/// The subcommand is given as the first option, e.g. bisect::start(); see find_first_bad to drive a whole bisection
+/// [Git doc](https://git-scm.com/docs/git-bisect)
+pub fn bisect(current_dir: Option<&str>) -> WrapCommand {
+    git(GIT_COMMAND, current_dir)
+}
@@ -0,0 +1,79 @@
+// Warning!! Code generated automatically: this file must not be edited by hand
+use crate::optionarg;
+use crate::wrap_command::FnOptionArg;
+
+pub const TERM_OLD: &str = "--term-old";
+pub const TERM_NEW: &str = "--term-new";
+pub const TERM_GOOD: &str = "--term-good";
+pub const TERM_BAD: &str = "--term-bad";
+pub const NO_CHECKOUT: &str = "--no-checkout";
+pub const FIRST_PARENT: &str = "--first-parent";
+pub const HYPHEN_HYPHEN: &str = "--";
+
+/// With start, use <term> instead of "good" (or "old") to mark commits that do not have the property.
+/// --term-old <term>
+pub fn term_old(term_arg: &str) -> FnOptionArg {
+    optionarg::with_parameter(TERM_OLD, term_arg)
+}
+
+/// With start, use <term> instead of "bad" (or "new") to mark commits that have the property.
+/// --term-new <term>
+pub fn term_new(term_arg: &str) -> FnOptionArg {
+    optionarg::with_parameter(TERM_NEW, term_arg)
+}
+
+/// With terms, print only the term used for old commits.
+/// --term-good
+pub fn term_good() -> FnOptionArg {
+    optionarg::simple(TERM_GOOD)
+}
+
+/// With terms, print only the term used for new commits.
+/// --term-bad
+pub fn term_bad() -> FnOptionArg {
+    optionarg::simple(TERM_BAD)
+}
+
+/// With start, do not checkout the new working tree at each iteration of the bisection process.
+/// Instead just update the reference named BISECT_HEAD to point to the commit that should be tested.
+/// --no-checkout
+pub fn no_checkout() -> FnOptionArg {
+    optionarg::simple(NO_CHECKOUT)
+}
+
+/// With start, follow only the first parent commit upon seeing a merge commit.
+/// --first-parent
+pub fn first_parent() -> FnOptionArg {
+    optionarg::simple(FIRST_PARENT)
+}
+
+/// Separates revisions from the paths the bisection is limited to.
+/// --
+pub fn hyphen_hyphen() -> FnOptionArg {
+    optionarg::simple(HYPHEN_HYPHEN)
+}
+
+/// Revision to mark (start, good, bad, skip) or to go back to (reset).
+/// Ranges such as <rev1>..<rev2> are accepted by skip.
+/// <rev>
+pub fn rev(rev: &str) -> FnOptionArg {
+    optionarg::value_parameter(rev)
+}
+
+/// With start, limit the bisection to commits touching the paths.
+/// <pathspec>
+pub fn pathspec(pathspec: &str) -> FnOptionArg {
+    optionarg::value_parameter(pathspec)
+}
+
+/// With replay, the file holding the bisect log to replay.
+/// <logfile>
+pub fn logfile(logfile: &str) -> FnOptionArg {
+    optionarg::value_parameter(logfile)
+}
+
+/// With run, the command telling whether the current revision is good (exit code 0), bad (exit code 1 to 127, but 125) or cannot be tested (125).
+/// <cmd>
+pub fn command(cmd: &str) -> FnOptionArg {
+    optionarg::value_parameter(cmd)
+}
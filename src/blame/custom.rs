@@ -0,0 +1,16 @@
+use crate::optionarg;
+use crate::blame::{DETECT_COPIES, DETECT_MOVES};
+use crate::wrap_command::FnOptionArg;
+
+/// Detect moved or copied lines within a file, a block needing at least <num> alphanumeric characters to be detected (default 20).
+/// -M<num>
+pub fn detect_moves_threshold(num: u32) -> FnOptionArg {
+    optionarg::value_parameter(format!("{DETECT_MOVES}{num}").as_str())
+}
+
+/// In addition to -M, detect lines moved or copied from other files that were modified in the same commit,
+/// a block needing at least <num> alphanumeric characters to be detected (default 40).
+/// -C<num>
+pub fn detect_copies_threshold(num: u32) -> FnOptionArg {
+    optionarg::value_parameter(format!("{DETECT_COPIES}{num}").as_str())
+}
@@ -0,0 +1,20 @@
+use crate::wrap_command::WrapCommand;
+use crate::git;
+
+mod options;
+pub use options::*;
+
+mod custom;
+pub use custom::*;
+
+mod parse;
+pub use parse::*;
+
+pub const GIT_COMMAND: &str = "blame";
+
+/// Show what revision and author last modified each line of a file.
+/// Annotates each line in the given file with information from the revision which last modified the line
+/// [Git doc](https://git-scm.com/docs/git-blame)
+pub fn blame(current_dir: Option<&str>) -> WrapCommand {
+    git(GIT_COMMAND, current_dir)
+}
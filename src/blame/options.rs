@@ -0,0 +1,194 @@
+// Warning!! Code generated automatically: this file must not be edited by hand
+use crate::optionarg;
+use crate::wrap_command::FnOptionArg;
+
+pub const BLANK_BOUNDARY: &str = "-b";
+pub const ROOT: &str = "--root";
+pub const SHOW_STATS: &str = "--show-stats";
+pub const LINE_RANGE: &str = "-L";
+pub const LONG_REV: &str = "-l";
+pub const RAW_TIMESTAMP: &str = "-t";
+pub const REVS_FILE: &str = "-S";
+pub const FIRST_PARENT: &str = "--first-parent";
+pub const PORCELAIN: &str = "--porcelain";
+pub const LINE_PORCELAIN: &str = "--line-porcelain";
+pub const INCREMENTAL: &str = "--incremental";
+pub const ENCODING: &str = "--encoding";
+pub const CONTENTS: &str = "--contents";
+pub const DATE: &str = "--date";
+pub const PROGRESS: &str = "--progress";
+pub const NO_PROGRESS: &str = "--no-progress";
+pub const DETECT_MOVES: &str = "-M";
+pub const DETECT_COPIES: &str = "-C";
+pub const IGNORE_REV: &str = "--ignore-rev";
+pub const IGNORE_REVS_FILE: &str = "--ignore-revs-file";
+pub const COLOR_LINES: &str = "--color-lines";
+pub const COLOR_BY_AGE: &str = "--color-by-age";
+pub const IGNORE_WHITESPACE: &str = "-w";
+pub const SINCE: &str = "--since";
+pub const HYPHEN_HYPHEN: &str = "--";
+
+/// Show blank SHA-1 for boundary commits.
+/// -b
+pub fn blank_boundary() -> FnOptionArg {
+    optionarg::simple(BLANK_BOUNDARY)
+}
+
+/// Do not treat root commits as boundaries.
+/// --root
+pub fn root() -> FnOptionArg {
+    optionarg::simple(ROOT)
+}
+
+/// Include additional statistics at the end of blame output.
+/// --show-stats
+pub fn show_stats() -> FnOptionArg {
+    optionarg::simple(SHOW_STATS)
+}
+
+/// Annotate only the line range given by <range>, either <start>,<end> or :<funcname>.
+/// May be specified multiple times.
+/// -L <range>
+pub fn line_range(range_arg: &str) -> FnOptionArg {
+    optionarg::with_parameter(LINE_RANGE, range_arg)
+}
+
+/// Show long rev (Default: off).
+/// -l
+pub fn long_rev() -> FnOptionArg {
+    optionarg::simple(LONG_REV)
+}
+
+/// Show raw timestamp (Default: off).
+/// -t
+pub fn raw_timestamp() -> FnOptionArg {
+    optionarg::simple(RAW_TIMESTAMP)
+}
+
+/// Use revisions from revs-file instead of calling git-rev-list(1).
+/// -S <revs-file>
+pub fn revs_file(revs_file_arg: &str) -> FnOptionArg {
+    optionarg::with_parameter(REVS_FILE, revs_file_arg)
+}
+
+/// Follow only the first parent commit upon seeing a merge commit.
+/// --first-parent
+pub fn first_parent() -> FnOptionArg {
+    optionarg::simple(FIRST_PARENT)
+}
+
+/// Show in a format designed for machine consumption.
+/// -p, --porcelain
+pub fn porcelain() -> FnOptionArg {
+    optionarg::simple(PORCELAIN)
+}
+
+/// Show the porcelain format, but output commit information for each line, not just the first time a commit is referenced.
+/// --line-porcelain
+pub fn line_porcelain() -> FnOptionArg {
+    optionarg::simple(LINE_PORCELAIN)
+}
+
+/// Show the result incrementally in a format designed for machine consumption.
+/// --incremental
+pub fn incremental() -> FnOptionArg {
+    optionarg::simple(INCREMENTAL)
+}
+
+/// Specifies the encoding used to output author names and commit summaries.
+/// --encoding=<encoding>
+pub fn encoding(encoding_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(ENCODING, encoding_arg)
+}
+
+/// Annotate using the contents from the named file, starting from <rev> if it is specified, and HEAD otherwise.
+/// --contents <file>
+pub fn contents(file_arg: &str) -> FnOptionArg {
+    optionarg::with_parameter(CONTENTS, file_arg)
+}
+
+/// Specifies the format used to output dates.
+/// --date <format>
+pub fn date(format_arg: &str) -> FnOptionArg {
+    optionarg::with_parameter(DATE, format_arg)
+}
+
+/// Progress status is reported on the standard error stream by default when it is attached to a terminal.
+/// --progress
+pub fn progress() -> FnOptionArg {
+    optionarg::simple(PROGRESS)
+}
+
+/// Do not report progress status.
+/// --no-progress
+pub fn no_progress() -> FnOptionArg {
+    optionarg::simple(NO_PROGRESS)
+}
+
+/// Detect moved or copied lines within a file.
+/// When a commit moves or copies a block of lines (e.g. the original file has A and then B, and the commit changes it to B and then A), the traditional blame algorithm notices only half of the movement and typically blames the lines that were moved up (i.e. B) to the parent and assigns blame to the lines that were moved down (i.e. A) to the child commit.
+/// -M
+pub fn detect_moves() -> FnOptionArg {
+    optionarg::simple(DETECT_MOVES)
+}
+
+/// In addition to -M, detect lines moved or copied from other files that were modified in the same commit.
+/// When this option is given twice, the command additionally looks for copies from other files in the commit that creates the file; given three times, from other files in any commit.
+/// -C
+pub fn detect_copies() -> FnOptionArg {
+    optionarg::simple(DETECT_COPIES)
+}
+
+/// Ignore changes made by the revision when assigning blame, as if the change never happened.
+/// --ignore-rev <rev>
+pub fn ignore_rev(rev_arg: &str) -> FnOptionArg {
+    optionarg::with_parameter(IGNORE_REV, rev_arg)
+}
+
+/// Ignore revisions listed in file, which must be in the same format as an fsck.skipList.
+/// --ignore-revs-file <file>
+pub fn ignore_revs_file(file_arg: &str) -> FnOptionArg {
+    optionarg::with_parameter(IGNORE_REVS_FILE, file_arg)
+}
+
+/// Color line annotations in the default format differently if they come from the same commit as the preceding line.
+/// --color-lines
+pub fn color_lines() -> FnOptionArg {
+    optionarg::simple(COLOR_LINES)
+}
+
+/// Color line annotations depending on the age of the line in the default format.
+/// --color-by-age
+pub fn color_by_age() -> FnOptionArg {
+    optionarg::simple(COLOR_BY_AGE)
+}
+
+/// Ignore whitespace when comparing the parent’s version and the child’s to find where the lines came from.
+/// -w
+pub fn ignore_whitespace() -> FnOptionArg {
+    optionarg::simple(IGNORE_WHITESPACE)
+}
+
+/// Do not look at commits older than the given date; the lines last changed before are blamed to a boundary commit.
+/// --since=<date>
+pub fn since(date_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(SINCE, date_arg)
+}
+
+/// Revision to annotate the file from.
+/// <rev>
+pub fn rev(rev: &str) -> FnOptionArg {
+    optionarg::value_parameter(rev)
+}
+
+/// Separates the revision from the file.
+/// --
+pub fn hyphen_hyphen() -> FnOptionArg {
+    optionarg::simple(HYPHEN_HYPHEN)
+}
+
+/// File to annotate.
+/// <file>
+pub fn file(file: &str) -> FnOptionArg {
+    optionarg::value_parameter(file)
+}
@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+use crate::parser::{parse_error, unquote_path};
+use crate::WrapError;
+
+/// The result of `git blame --porcelain` or `git blame --line-porcelain`:
+/// the annotated lines, and the commits they are blamed to, each listed once
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Blame {
+    /// Lines in the order git outputs them, i.e. by final line number
+    pub lines: Vec<BlameLine>,
+    /// Commit metadata, keyed by object name
+    pub commits: HashMap<String, BlameCommit>,
+}
+
+impl Blame {
+    /// Metadata of the commit a line is blamed to
+    pub fn commit(&self, line: &BlameLine) -> Option<&BlameCommit> {
+        self.commits.get(line.oid.as_str())
+    }
+}
+
+/// A line of the annotated file
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlameLine {
+    /// Commit the line is blamed to
+    pub oid: String,
+    /// Line number in the file as of the blamed commit, starting at 1
+    pub original_line: u32,
+    /// Line number in the annotated file, starting at 1
+    pub final_line: u32,
+    /// Path of the file in the blamed commit, which differs from the annotated one across renames or with -C
+    pub filename: String,
+    /// Line content, without its line terminator
+    pub content: String,
+}
+
+/// Metadata of a commit lines are blamed to
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlameCommit {
+    pub oid: String,
+    pub author: String,
+    /// Author email, angle brackets included, e.g. `<author@example.com>`
+    pub author_mail: String,
+    /// Seconds since the UNIX epoch
+    pub author_time: i64,
+    /// Author timezone, e.g. `+0200`
+    pub author_tz: String,
+    pub committer: String,
+    pub committer_mail: String,
+    pub committer_time: i64,
+    pub committer_tz: String,
+    /// First line of the commit message
+    pub summary: String,
+    /// True if the commit is a boundary of the blamed range (root commit, `--since` limit...)
+    pub boundary: bool,
+    /// Parent commit and path the lines were blamed from, None for a boundary commit
+    pub previous: Option<(String, String)>,
+}
+
+/// Parses the output of `git blame --porcelain` or `git blame --line-porcelain`
+pub fn parse_blame_porcelain(output: &str) -> Result<Blame, WrapError> {
+    let mut blame = Blame::default();
+    let mut filenames: HashMap<String, String> = HashMap::new();
+    let mut current: Option<(String, u32, u32)> = None;
+
+    for line in output.lines() {
+        if let Some(content) = line.strip_prefix('\t') {
+            let (oid, original_line, final_line) = current.take().ok_or_else(|| parse_error("blame line", line))?;
+            blame.lines.push(BlameLine {
+                filename: filenames.get(oid.as_str()).cloned().unwrap_or_default(),
+                oid,
+                original_line,
+                final_line,
+                content: String::from(content),
+            });
+            continue;
+        }
+
+        let Some((oid, _, _)) = current.as_ref() else {
+            let header = parse_header(line)?;
+            blame.commits.entry(header.0.clone()).or_insert_with(|| empty_commit(header.0.clone()));
+            current = Some(header);
+            continue;
+        };
+
+        let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+        if key == "filename" {
+            filenames.insert(oid.clone(), unquote_path(value));
+            continue;
+        }
+        let Some(commit) = blame.commits.get_mut(oid.as_str()) else {
+            continue;
+        };
+        match key {
+            "author" => commit.author = String::from(value),
+            "author-mail" => commit.author_mail = String::from(value),
+            "author-time" => commit.author_time = value.parse().map_err(|_| parse_error("author time", line))?,
+            "author-tz" => commit.author_tz = String::from(value),
+            "committer" => commit.committer = String::from(value),
+            "committer-mail" => commit.committer_mail = String::from(value),
+            "committer-time" => commit.committer_time = value.parse().map_err(|_| parse_error("committer time", line))?,
+            "committer-tz" => commit.committer_tz = String::from(value),
+            "summary" => commit.summary = String::from(value),
+            "boundary" => commit.boundary = true,
+            "previous" => commit.previous = value.split_once(' ')
+                .map(|(oid, path)| (String::from(oid), unquote_path(path))),
+            _ => {}
+        }
+    }
+
+    Ok(blame)
+}
+
+// <oid> <original line> <final line> [<lines in group>]
+fn parse_header(line: &str) -> Result<(String, u32, u32), WrapError> {
+    let mut f = line.split(' ');
+    let (Some(oid), Some(original_line), Some(final_line)) = (f.next(), f.next(), f.next()) else {
+        return Err(parse_error("blame header", line));
+    };
+    if !oid.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(parse_error("blame header", line));
+    }
+    Ok((
+        String::from(oid),
+        original_line.parse().map_err(|_| parse_error("blame header", line))?,
+        final_line.parse().map_err(|_| parse_error("blame header", line))?,
+    ))
+}
+
+fn empty_commit(oid: String) -> BlameCommit {
+    BlameCommit {
+        oid,
+        author: String::new(),
+        author_mail: String::new(),
+        author_time: 0,
+        author_tz: String::new(),
+        committer: String::new(),
+        committer_mail: String::new(),
+        committer_time: 0,
+        committer_tz: String::new(),
+        summary: String::new(),
+        boundary: false,
+        previous: None,
+    }
+}
@@ -0,0 +1,17 @@
+use crate::wrap_command::WrapCommand;
+use crate::git;
+
+mod options;
+pub use options::*;
+
+mod session;
+pub use session::*;
+
+pub const GIT_COMMAND: &str = "cat-file";
+
+/// Provide contents or details of repository objects.
+/// Output the contents or other properties such as size, type or delta information of one or more objects.
+/// [Git doc](https://git-scm.com/docs/git-cat-file)
+pub fn cat_file(current_dir: Option<&str>) -> WrapCommand {
+    git(GIT_COMMAND, current_dir)
+}
@@ -0,0 +1,116 @@
+// Warning!! Code generated automatically: this file must not be edited by hand
+use crate::optionarg;
+use crate::wrap_command::FnOptionArg;
+
+pub const SHOW_TYPE: &str = "-t";
+pub const SHOW_SIZE: &str = "-s";
+pub const EXISTS: &str = "-e";
+pub const PRETTY_PRINT: &str = "-p";
+pub const TEXTCONV: &str = "--textconv";
+pub const FILTERS: &str = "--filters";
+pub const PATH: &str = "--path";
+pub const BATCH: &str = "--batch";
+pub const BATCH_CHECK: &str = "--batch-check";
+pub const BATCH_ALL_OBJECTS: &str = "--batch-all-objects";
+pub const BUFFER: &str = "--buffer";
+pub const UNORDERED: &str = "--unordered";
+pub const FOLLOW_SYMLINKS: &str = "--follow-symlinks";
+pub const ALLOW_UNKNOWN_TYPE: &str = "--allow-unknown-type";
+
+/// Instead of the content, show the object type identified by <object>.
+/// -t
+pub fn show_type() -> FnOptionArg {
+    optionarg::simple(SHOW_TYPE)
+}
+
+/// Instead of the content, show the object size identified by <object>.
+/// -s
+pub fn show_size() -> FnOptionArg {
+    optionarg::simple(SHOW_SIZE)
+}
+
+/// Exit with zero status if <object> exists and is a valid object.
+/// If <object> is of an invalid format, exit with non-zero status and emit an error on stderr.
+/// -e
+pub fn exists() -> FnOptionArg {
+    optionarg::simple(EXISTS)
+}
+
+/// Pretty-print the contents of <object> based on its type.
+/// -p
+pub fn pretty_print() -> FnOptionArg {
+    optionarg::simple(PRETTY_PRINT)
+}
+
+/// Show the content as transformed by a textconv filter.
+/// --textconv
+pub fn textconv() -> FnOptionArg {
+    optionarg::simple(TEXTCONV)
+}
+
+/// Show the content as converted by the filters configured in the current working tree for the given <path> (i.e. smudge filters, end-of-line conversion, etc).
+/// --filters
+pub fn filters() -> FnOptionArg {
+    optionarg::simple(FILTERS)
+}
+
+/// For use with --textconv or --filters, to allow specifying an object name and a path separately.
+/// --path=<path>
+pub fn path(path_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(PATH, path_arg)
+}
+
+/// Print object information and contents for each object provided on stdin.
+/// --batch
+pub fn batch() -> FnOptionArg {
+    optionarg::simple(BATCH)
+}
+
+/// Print object information for each object provided on stdin.
+/// --batch-check
+pub fn batch_check() -> FnOptionArg {
+    optionarg::simple(BATCH_CHECK)
+}
+
+/// Instead of reading a list of objects on stdin, perform the requested batch operation on all objects in the repository and any alternate object stores (not just reachable objects).
+/// --batch-all-objects
+pub fn batch_all_objects() -> FnOptionArg {
+    optionarg::simple(BATCH_ALL_OBJECTS)
+}
+
+/// Normally batch output is flushed after each object is output, so that a process can interactively read and write from cat-file.
+/// With this option, the output uses normal stdio buffering; this is much more efficient when invoking --batch-check or --batch-command on a large number of objects.
+/// --buffer
+pub fn buffer() -> FnOptionArg {
+    optionarg::simple(BUFFER)
+}
+
+/// When --batch-all-objects is in use, visit objects in an order which may be more efficient for accessing the object contents than hash order.
+/// --unordered
+pub fn unordered() -> FnOptionArg {
+    optionarg::simple(UNORDERED)
+}
+
+/// With --batch or --batch-check, follow symlinks inside the repository when requesting objects with extended SHA-1 expressions of the form tree-ish:path-in-tree.
+/// --follow-symlinks
+pub fn follow_symlinks() -> FnOptionArg {
+    optionarg::simple(FOLLOW_SYMLINKS)
+}
+
+/// Allow -s or -t to query broken/corrupt objects of unknown type.
+/// --allow-unknown-type
+pub fn allow_unknown_type() -> FnOptionArg {
+    optionarg::simple(ALLOW_UNKNOWN_TYPE)
+}
+
+/// Typically this matches the real type of <object> but asking for a type that can trivially be dereferenced from the given <object> is also permitted.
+/// <kind>
+pub fn kind(kind: &str) -> FnOptionArg {
+    optionarg::value_parameter(kind)
+}
+
+/// The name of the object to show.
+/// <object>
+pub fn object(object: &str) -> FnOptionArg {
+    optionarg::value_parameter(object)
+}
@@ -0,0 +1,207 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::process::{Child, ChildStdin, ChildStdout};
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError, Sender};
+use std::thread::{self, JoinHandle};
+use crate::cat_file::BATCH_CHECK;
+use crate::control::{Control, POLL_INTERVAL};
+use crate::parser::parse_error;
+use crate::wrap_command::{WrapCommand, WrapOutput};
+use crate::WrapError;
+
+/// Kind of a git object
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ObjectKind {
+    Blob,
+    Tree,
+    Commit,
+    Tag,
+}
+
+impl ObjectKind {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "blob" => Some(ObjectKind::Blob),
+            "tree" => Some(ObjectKind::Tree),
+            "commit" => Some(ObjectKind::Commit),
+            "tag" => Some(ObjectKind::Tag),
+            _ => None,
+        }
+    }
+}
+
+/// An object read by a [BatchSession]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Object {
+    /// Full object name
+    pub oid: String,
+    pub kind: ObjectKind,
+    /// Size of the content in bytes
+    pub size: u64,
+    /// Raw content, empty for a `--batch-check` session
+    pub data: Vec<u8>,
+}
+
+/// The answer of a [BatchSession] to an object name
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BatchResult {
+    Found(Object),
+    /// No object has the requested name
+    Missing(String),
+    /// The requested abbreviated name matches several objects
+    Ambiguous(String),
+}
+
+/// A long-lived `git cat-file --batch` or `git cat-file --batch-check` process,
+/// answering any number of object requests without spawning git again.
+/// The timeout and cancel handle of the command apply to the whole session, a request waiting for its reply included.
+/// The process ends when the session is closed or dropped.
+pub struct BatchSession {
+    child: Child,
+    stdin: Option<ChildStdin>,
+    replies: Receiver<Reply>,
+    stderr: Option<JoinHandle<Vec<u8>>>,
+    control: Control,
+}
+
+// the header line of a reply, without its newline, and the object contents following it
+type Reply = (String, Vec<u8>);
+
+/// Starts a session running [cmd], a `cat-file` command built with the [crate::cat_file::batch] or
+/// [crate::cat_file::batch_check] option, without a custom format nor `--buffer`
+pub fn batch_session(cmd: &WrapCommand) -> Result<BatchSession, WrapError> {
+    let contents = !cmd.dry_run_args().argv.iter().any(|a| a == BATCH_CHECK);
+    let (mut child, control) = cmd.spawn_piped()?;
+    let (Some(stdin), Some(stdout), Some(mut stderr)) = (child.stdin.take(), child.stdout.take(), child.stderr.take()) else {
+        return Err(control.failed());
+    };
+    // replies are read on their own thread, so a request can be aborted while git has not answered it
+    let (tx, replies) = channel();
+    thread::spawn(move || read_replies(stdout, contents, tx));
+    Ok(BatchSession {
+        child,
+        stdin: Some(stdin),
+        replies,
+        stderr: Some(thread::spawn(move || {
+            let mut buf = Vec::new();
+            let _ = stderr.read_to_end(&mut buf);
+            buf
+        })),
+        control,
+    })
+}
+
+impl BatchSession {
+    /// Reads the object named [name], e.g. a full or abbreviated object name or `HEAD:src/lib.rs`
+    pub fn get(&mut self, name: &str) -> Result<BatchResult, WrapError> {
+        if name.contains('\n') {
+            return Err(parse_error("object name", name));
+        }
+        if let Some(e) = self.control.check() {
+            self.control.abort(&mut self.child);
+            return Err(e);
+        }
+        let Some(stdin) = self.stdin.as_mut() else {
+            return Err(self.control.failed());
+        };
+        if stdin.write_all(format!("{name}\n").as_bytes()).and_then(|_| stdin.flush()).is_err() {
+            return Err(self.terminated());
+        }
+
+        let (header, data) = self.reply()?;
+        let header = header.as_str();
+        if let Some(missing) = header.strip_suffix(" missing") {
+            return Ok(BatchResult::Missing(String::from(missing)));
+        }
+        if let Some(ambiguous) = header.strip_suffix(" ambiguous") {
+            return Ok(BatchResult::Ambiguous(String::from(ambiguous)));
+        }
+
+        // <oid> <type> <size>
+        let mut f = header.split(' ');
+        let (Some(oid), Some(kind), Some(size), None) = (f.next(), f.next(), f.next(), f.next()) else {
+            return Err(parse_error("cat-file header", header));
+        };
+        let kind = ObjectKind::from_name(kind).ok_or_else(|| parse_error("object type", kind))?;
+        let size: u64 = size.parse().map_err(|_| parse_error("object size", size))?;
+
+        Ok(BatchResult::Found(Object { oid: String::from(oid), kind, size, data }))
+    }
+
+    /// Ends the session, reporting a git failure
+    pub fn close(mut self) -> Result<(), WrapError> {
+        // closing stdin lets git exit once it has answered every request
+        self.stdin.take();
+        let status = self.control.wait(&mut self.child)?;
+        let stderr = self.stderr.take().and_then(|r| r.join().ok()).unwrap_or_default();
+        if status.success() {
+            Ok(())
+        } else {
+            Err(WrapError::classify(WrapOutput::new(status.code(), Vec::new(), stderr)))
+        }
+    }
+
+    // waits for the reply to the last request, aborting git if the session is cancelled or runs out of time
+    fn reply(&mut self) -> Result<Reply, WrapError> {
+        if self.control.is_unbounded() {
+            return self.replies.recv().map_err(|_| self.terminated());
+        }
+        loop {
+            if let Some(e) = self.control.check() {
+                self.control.abort(&mut self.child);
+                return Err(e);
+            }
+            match self.replies.recv_timeout(POLL_INTERVAL) {
+                Ok(reply) => return Ok(reply),
+                Err(RecvTimeoutError::Disconnected) => return Err(self.terminated()),
+                Err(RecvTimeoutError::Timeout) => {}
+            }
+        }
+    }
+
+    // the error to report once git stopped answering: its own failure if it exited with one.
+    // Closing stdin makes sure git is not left waiting for a request.
+    fn terminated(&mut self) -> WrapError {
+        self.stdin.take();
+        match self.child.wait() {
+            Ok(status) if !status.success() && status.code().is_some() => {
+                let stderr = self.stderr.take().and_then(|r| r.join().ok()).unwrap_or_default();
+                WrapError::classify(WrapOutput::new(status.code(), Vec::new(), stderr))
+            }
+            _ => self.control.failed(),
+        }
+    }
+}
+
+impl Drop for BatchSession {
+    fn drop(&mut self) {
+        if self.stdin.take().is_some() {
+            let _ = self.child.kill();
+            let _ = self.child.wait();
+        }
+    }
+}
+
+// <oid> <type> <size> headers are followed by the contents and a newline with --batch,
+// `<name> missing` and `<name> ambiguous` ones by nothing
+fn read_replies(stdout: ChildStdout, contents: bool, tx: Sender<Reply>) {
+    let mut reader = BufReader::new(stdout);
+    loop {
+        let mut header = String::new();
+        match reader.read_line(&mut header) {
+            Ok(0) | Err(_) => return,
+            Ok(_) => {}
+        }
+        header.truncate(header.trim_end_matches('\n').len());
+        let mut data = Vec::new();
+        if let Some(size) = header.rsplit(' ').next().and_then(|s| s.parse::<usize>().ok()).filter(|_| contents) {
+            data.resize(size + 1, 0);
+            if reader.read_exact(&mut data).is_err() {
+                return;
+            }
+            data.truncate(size);
+        }
+        if tx.send((header, data)).is_err() {
+            return;
+        }
+    }
+}
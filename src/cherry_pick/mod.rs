@@ -0,0 +1,14 @@
+use crate::wrap_command::WrapCommand;
+use crate::git;
+
+mod options;
+pub use options::*;
+
+pub const GIT_COMMAND: &str = "cherry-pick";
+
+/// Apply the changes introduced by some existing commits.
+/// Given one or more existing commits, apply the change each one introduces, recording a new commit for each
+/// [Git doc](https://git-scm.com/docs/git-cherry-pick)
+pub fn cherry_pick(current_dir: Option<&str>) -> WrapCommand {
+    git(GIT_COMMAND, current_dir)
+}
@@ -0,0 +1,153 @@
+// Warning!! Code generated automatically: this file must not be edited by hand
+use crate::optionarg;
+use crate::wrap_command::FnOptionArg;
+
+pub const EDIT: &str = "--edit";
+pub const CLEANUP: &str = "--cleanup";
+pub const RECORD_ORIGIN: &str = "-x";
+pub const MAINLINE: &str = "--mainline";
+pub const NO_COMMIT: &str = "--no-commit";
+pub const SIGNOFF: &str = "--signoff";
+pub const FF: &str = "--ff";
+pub const ALLOW_EMPTY: &str = "--allow-empty";
+pub const ALLOW_EMPTY_MESSAGE: &str = "--allow-empty-message";
+pub const KEEP_REDUNDANT_COMMITS: &str = "--keep-redundant-commits";
+pub const EMPTY: &str = "--empty";
+pub const STRATEGY: &str = "--strategy";
+pub const STRATEGY_OPTION: &str = "--strategy-option";
+pub const RERERE_AUTOUPDATE: &str = "--rerere-autoupdate";
+pub const NO_RERERE_AUTOUPDATE: &str = "--no-rerere-autoupdate";
+pub const CONTINUE_CHERRY_PICK: &str = "--continue";
+pub const SKIP: &str = "--skip";
+pub const QUIT: &str = "--quit";
+pub const ABORT: &str = "--abort";
+
+/// With this option, git cherry-pick will let you edit the commit message prior to committing.
+/// -e, --edit
+pub fn edit() -> FnOptionArg {
+    optionarg::simple(EDIT)
+}
+
+/// This option determines how the commit message will be cleaned up before being passed on to the commit machinery.
+/// --cleanup=<mode>
+pub fn cleanup(mode_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(CLEANUP, mode_arg)
+}
+
+/// When recording the commit, append a line that says "(cherry picked from commit …)" to the original commit message in order to indicate which commit this change was cherry-picked from.
+/// -x
+pub fn record_origin() -> FnOptionArg {
+    optionarg::simple(RECORD_ORIGIN)
+}
+
+/// Usually you cannot cherry-pick a merge because you do not know which side of the merge should be considered the mainline.
+/// This option specifies the parent number (starting from 1) of the mainline and allows cherry-pick to replay the change relative to the specified parent.
+/// -m <parent-number>, --mainline <parent-number>
+pub fn mainline(parent_number_arg: &str) -> FnOptionArg {
+    optionarg::with_parameter(MAINLINE, parent_number_arg)
+}
+
+/// Usually the command automatically creates a sequence of commits.
+/// This flag applies the changes necessary to cherry-pick each named commit to your working tree and the index, without making any commit.
+/// -n, --no-commit
+pub fn no_commit() -> FnOptionArg {
+    optionarg::simple(NO_COMMIT)
+}
+
+/// Add a Signed-off-by trailer at the end of the commit message.
+/// -s, --signoff
+pub fn signoff() -> FnOptionArg {
+    optionarg::simple(SIGNOFF)
+}
+
+/// If the current HEAD is the same as the parent of the cherry-pick’ed commit, then a fast forward to this commit will be performed.
+/// --ff
+pub fn ff() -> FnOptionArg {
+    optionarg::simple(FF)
+}
+
+/// By default, cherry-picking an empty commit will fail, indicating that an explicit invocation of git commit --allow-empty is required.
+/// This option overrides that behavior, allowing empty commits to be preserved automatically in a cherry-pick.
+/// --allow-empty
+pub fn allow_empty() -> FnOptionArg {
+    optionarg::simple(ALLOW_EMPTY)
+}
+
+/// By default, cherry-picking a commit with an empty message will fail.
+/// This option overrides that behavior, allowing commits with empty messages to be cherry picked.
+/// --allow-empty-message
+pub fn allow_empty_message() -> FnOptionArg {
+    optionarg::simple(ALLOW_EMPTY_MESSAGE)
+}
+
+/// If a commit being cherry picked duplicates a commit already in the current history, it will become empty.
+/// By default these redundant commits cause cherry-pick to stop so the user can examine the commit.
+/// This option overrides that behavior and creates an empty commit object.
+/// --keep-redundant-commits
+pub fn keep_redundant_commits() -> FnOptionArg {
+    optionarg::simple(KEEP_REDUNDANT_COMMITS)
+}
+
+/// How to handle commits being cherry-picked that are redundant with changes already in the current history.
+/// --empty=(drop|keep|stop)
+pub fn empty(value: &str) -> FnOptionArg {
+    optionarg::equal_optional(EMPTY, value)
+}
+
+/// Use the given merge strategy.
+/// Should only be used once.
+/// --strategy=<strategy>
+pub fn strategy(strategy_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(STRATEGY, strategy_arg)
+}
+
+/// Pass the merge strategy-specific option through to the merge strategy.
+/// -X<option>, --strategy-option=<option>
+pub fn strategy_option(option_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(STRATEGY_OPTION, option_arg)
+}
+
+/// After the rerere mechanism reuses a recorded resolution on the current conflict to update the files in the working tree, allow it to also update the index with the result of resolution.
+/// --rerere-autoupdate
+pub fn rerere_autoupdate() -> FnOptionArg {
+    optionarg::simple(RERERE_AUTOUPDATE)
+}
+
+/// Do not let rerere update the index with the result of resolution.
+/// --no-rerere-autoupdate
+pub fn no_rerere_autoupdate() -> FnOptionArg {
+    optionarg::simple(NO_RERERE_AUTOUPDATE)
+}
+
+/// Continue the operation in progress using the information in .git/sequencer.
+/// Can be used to continue after resolving conflicts in a failed cherry-pick or revert.
+/// --continue
+pub fn continue_cherry_pick() -> FnOptionArg {
+    optionarg::simple(CONTINUE_CHERRY_PICK)
+}
+
+/// Skip the current commit and continue with the rest of the sequence.
+/// --skip
+pub fn skip() -> FnOptionArg {
+    optionarg::simple(SKIP)
+}
+
+/// Forget about the current operation in progress.
+/// Can be used to clear the sequencer state after a failed cherry-pick or revert.
+/// --quit
+pub fn quit() -> FnOptionArg {
+    optionarg::simple(QUIT)
+}
+
+/// Cancel the operation and return to the pre-sequence state.
+/// --abort
+pub fn abort() -> FnOptionArg {
+    optionarg::simple(ABORT)
+}
+
+/// Commits to cherry-pick.
+/// Ranges such as <commit>..<commit> are accepted.
+/// <commit>
+pub fn commit(commit: &str) -> FnOptionArg {
+    optionarg::value_parameter(commit)
+}
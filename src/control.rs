@@ -0,0 +1,161 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Child, ExitStatus};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+use crate::WrapError;
+
+/// Interval between two checks of the timeout and cancellation state of a running command
+pub(crate) const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// A handle used to cancel a git command from another thread.
+/// Cancelling kills the git process and its process group; the execution then fails with [WrapError::Cancelled].
+#[derive(Clone, Debug, Default)]
+pub struct CancelHandle {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancelHandle {
+    /// Requests the cancellation of the command.
+    /// A cancelled command stays cancelled: any later execution is aborted as soon as it starts.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns true if the cancellation was requested
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+/// Deadline, cancellation and cleanup state of a single command execution
+pub(crate) struct Control {
+    command: String,
+    deadline: Option<Instant>,
+    cancel: Option<CancelHandle>,
+    cleanup: Vec<(PathBuf, CleanupKind)>,
+}
+
+/// What an abort does to a cleanup path, depending on its state when the execution started
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CleanupKind {
+    /// Missing at start: removed
+    Remove,
+    /// An empty directory at start: emptied
+    Empty,
+    /// Holding data at start: left alone, as it is not the execution's to remove
+    Keep,
+}
+
+impl Control {
+    pub(crate) fn new(command: String, timeout: Option<Duration>, cancel: Option<CancelHandle>, cleanup: &[PathBuf]) -> Self {
+        Self {
+            command,
+            deadline: timeout.map(|t| Instant::now() + t),
+            cancel,
+            cleanup: cleanup.iter().map(|p| (p.clone(), cleanup_kind(p))).collect(),
+        }
+    }
+
+    /// Returns true if the execution has neither a deadline nor a cancel handle to watch
+    pub(crate) fn is_unbounded(&self) -> bool {
+        self.deadline.is_none() && self.cancel.is_none()
+    }
+
+    /// Returns the error the execution must be aborted with, if it was cancelled or ran out of time
+    pub(crate) fn check(&self) -> Option<WrapError> {
+        if self.cancel.as_ref().is_some_and(|c| c.is_cancelled()) {
+            Some(WrapError::Cancelled(self.command.clone()))
+        } else if self.deadline.is_some_and(|d| Instant::now() >= d) {
+            Some(WrapError::Timeout(self.command.clone()))
+        } else {
+            None
+        }
+    }
+
+    /// Waits for [child] to exit, aborting it if the execution is cancelled or runs out of time
+    pub(crate) fn wait(&self, child: &mut Child) -> Result<ExitStatus, WrapError> {
+        if self.is_unbounded() {
+            return child.wait().map_err(|_| self.failed());
+        }
+        loop {
+            if let Some(status) = child.try_wait().map_err(|_| self.failed())? {
+                return Ok(status);
+            }
+            if let Some(e) = self.check() {
+                self.abort(child);
+                return Err(e);
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+
+    /// The error reported when the process could not be spawned or waited for
+    pub(crate) fn failed(&self) -> WrapError {
+        WrapError::FailedExecuteProcess(self.command.clone())
+    }
+
+    /// Kills [child] along with its process group, then removes whatever it left in the cleanup paths
+    pub(crate) fn abort(&self, child: &mut Child) {
+        self.kill(child);
+        self.cleanup();
+    }
+
+    /// Kills [child] along with its process group and reaps it, leaving the cleanup paths as they are
+    pub(crate) fn kill(&self, child: &mut Child) {
+        // unbounded commands share the process group of the caller
+        if !self.is_unbounded() {
+            kill_process_group(child.id());
+        }
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+
+    /// Removes whatever an aborted execution left in the cleanup paths
+    pub(crate) fn cleanup(&self) {
+        for (path, kind) in &self.cleanup {
+            match kind {
+                CleanupKind::Remove => {
+                    let _ = fs::remove_dir_all(path);
+                }
+                CleanupKind::Empty => empty_dir(path),
+                CleanupKind::Keep => {}
+            }
+        }
+    }
+}
+
+/// Kills the process group led by process [id].
+/// Only bounded commands lead their own process group (see WrapCommand::command): [id] must be one of them.
+#[cfg(unix)]
+pub(crate) fn kill_process_group(id: u32) {
+    use rustix::process::{Pid, Signal};
+    if let Some(pid) = i32::try_from(id).ok().and_then(Pid::from_raw) {
+        let _ = rustix::process::kill_process_group(pid, Signal::KILL);
+    }
+}
+
+/// Kills the process group led by process [id]
+#[cfg(not(unix))]
+pub(crate) fn kill_process_group(_id: u32) {}
+
+fn cleanup_kind(path: &Path) -> CleanupKind {
+    if !path.exists() {
+        CleanupKind::Remove
+    } else if fs::read_dir(path).is_ok_and(|mut entries| entries.next().is_none()) {
+        CleanupKind::Empty
+    } else {
+        CleanupKind::Keep
+    }
+}
+
+fn empty_dir(path: &Path) {
+    if let Ok(entries) = fs::read_dir(path) {
+        for entry in entries.flatten() {
+            let p = entry.path();
+            let _ = if p.is_dir() { fs::remove_dir_all(p) } else { fs::remove_file(p) };
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use crate::wrap_command::WrapCommand;
+use crate::git;
+
+mod options;
+pub use options::*;
+mod parse;
+pub use parse::*;
+
+pub const GIT_COMMAND: &str = "diff";
+
+/// Show changes between commits, commit and working tree, etc.
+/// Show changes between the working tree and the index or a tree, changes between the index and a tree, changes between two trees, changes resulting from a merge, changes between two blob objects, or changes between two files on disk
+/// [Git doc](https://git-scm.com/docs/git-diff)
+pub fn diff(current_dir: Option<&str>) -> WrapCommand {
+    git(GIT_COMMAND, current_dir)
+}
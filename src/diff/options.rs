@@ -0,0 +1,316 @@
+// Warning!! Code generated automatically: this file must not be edited by hand
+use crate::optionarg;
+use crate::wrap_command::FnOptionArg;
+
+pub const CACHED: &str = "--cached";
+pub const STAGED: &str = "--staged";
+pub const MERGE_BASE: &str = "--merge-base";
+pub const NO_INDEX: &str = "--no-index";
+pub const PATCH: &str = "--patch";
+pub const NO_PATCH: &str = "--no-patch";
+pub const UNIFIED: &str = "--unified";
+pub const RAW: &str = "--raw";
+pub const PATCH_WITH_RAW: &str = "--patch-with-raw";
+pub const MINIMAL: &str = "--minimal";
+pub const PATIENCE: &str = "--patience";
+pub const HISTOGRAM: &str = "--histogram";
+pub const DIFF_ALGORITHM: &str = "--diff-algorithm";
+pub const STAT: &str = "--stat";
+pub const NUMSTAT: &str = "--numstat";
+pub const SHORTSTAT: &str = "--shortstat";
+pub const SUMMARY: &str = "--summary";
+pub const NAME_ONLY: &str = "--name-only";
+pub const NAME_STATUS: &str = "--name-status";
+pub const NULL: &str = "-z";
+pub const FULL_INDEX: &str = "--full-index";
+pub const BINARY: &str = "--binary";
+pub const ABBREV: &str = "--abbrev";
+pub const FIND_RENAMES: &str = "--find-renames";
+pub const FIND_COPIES: &str = "--find-copies";
+pub const FIND_COPIES_HARDER: &str = "--find-copies-harder";
+pub const NO_RENAMES: &str = "--no-renames";
+pub const DIFF_FILTER: &str = "--diff-filter";
+pub const REVERSE: &str = "-R";
+pub const RELATIVE: &str = "--relative";
+pub const TEXT: &str = "--text";
+pub const IGNORE_SPACE_CHANGE: &str = "--ignore-space-change";
+pub const IGNORE_ALL_SPACE: &str = "--ignore-all-space";
+pub const IGNORE_BLANK_LINES: &str = "--ignore-blank-lines";
+pub const EXIT_CODE: &str = "--exit-code";
+pub const QUIET: &str = "--quiet";
+pub const NO_COLOR: &str = "--no-color";
+pub const NO_EXT_DIFF: &str = "--no-ext-diff";
+pub const NO_TEXTCONV: &str = "--no-textconv";
+pub const SUBMODULE: &str = "--submodule";
+pub const HYPHEN_HYPHEN: &str = "--";
+
+/// View the changes you staged for the next commit relative to the named <commit>.
+/// Typically you would want comparison with the latest commit, so if you do not give <commit>, it defaults to HEAD.
+/// --cached
+pub fn cached() -> FnOptionArg {
+    optionarg::simple(CACHED)
+}
+
+/// This is a synonym for --cached.
+/// --staged
+pub fn staged() -> FnOptionArg {
+    optionarg::simple(STAGED)
+}
+
+/// Instead of comparing with <commit> directly, use the merge base of <commit> and HEAD.
+/// --merge-base
+pub fn merge_base() -> FnOptionArg {
+    optionarg::simple(MERGE_BASE)
+}
+
+/// Compare the given two paths on the filesystem.
+/// --no-index
+pub fn no_index() -> FnOptionArg {
+    optionarg::simple(NO_INDEX)
+}
+
+/// Generate patch.
+/// This is the default.
+/// -p, --patch
+pub fn patch() -> FnOptionArg {
+    optionarg::simple(PATCH)
+}
+
+/// Suppress all output from the diff machinery.
+/// -s, --no-patch
+pub fn no_patch() -> FnOptionArg {
+    optionarg::simple(NO_PATCH)
+}
+
+/// Generate diffs with <n> lines of context instead of the usual three.
+/// Implies --patch.
+/// -U<n>, --unified=<n>
+pub fn unified(n_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(UNIFIED, n_arg)
+}
+
+/// Generate the diff in raw format.
+/// --raw
+pub fn raw() -> FnOptionArg {
+    optionarg::simple(RAW)
+}
+
+/// Synonym for -p --raw.
+/// --patch-with-raw
+pub fn patch_with_raw() -> FnOptionArg {
+    optionarg::simple(PATCH_WITH_RAW)
+}
+
+/// Spend extra time to make sure the smallest possible diff is produced.
+/// --minimal
+pub fn minimal() -> FnOptionArg {
+    optionarg::simple(MINIMAL)
+}
+
+/// Generate a diff using the "patience diff" algorithm.
+/// --patience
+pub fn patience() -> FnOptionArg {
+    optionarg::simple(PATIENCE)
+}
+
+/// Generate a diff using the "histogram diff" algorithm.
+/// --histogram
+pub fn histogram() -> FnOptionArg {
+    optionarg::simple(HISTOGRAM)
+}
+
+/// Choose a diff algorithm: default, myers, minimal, patience or histogram.
+/// --diff-algorithm=<algorithm>
+pub fn diff_algorithm(algorithm_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(DIFF_ALGORITHM, algorithm_arg)
+}
+
+/// Generate a diffstat.
+/// --stat
+pub fn stat() -> FnOptionArg {
+    optionarg::simple(STAT)
+}
+
+/// Similar to --stat, but shows number of added and deleted lines in decimal notation and pathname without abbreviation, to make it more machine friendly.
+/// For binary files, outputs two - instead of saying 0 0.
+/// --numstat
+pub fn numstat() -> FnOptionArg {
+    optionarg::simple(NUMSTAT)
+}
+
+/// Output only the last line of the --stat format containing total number of modified files, as well as number of added and deleted lines.
+/// --shortstat
+pub fn shortstat() -> FnOptionArg {
+    optionarg::simple(SHORTSTAT)
+}
+
+/// Output a condensed summary of extended header information such as creations, renames and mode changes.
+/// --summary
+pub fn summary() -> FnOptionArg {
+    optionarg::simple(SUMMARY)
+}
+
+/// Show only names of changed files.
+/// --name-only
+pub fn name_only() -> FnOptionArg {
+    optionarg::simple(NAME_ONLY)
+}
+
+/// Show only names and status of changed files.
+/// --name-status
+pub fn name_status() -> FnOptionArg {
+    optionarg::simple(NAME_STATUS)
+}
+
+/// When --raw, --numstat, --name-only or --name-status has been given, do not munge pathnames and use NULs as output field terminators.
+/// -z
+pub fn null() -> FnOptionArg {
+    optionarg::simple(NULL)
+}
+
+/// Instead of the first handful of characters, show the full pre- and post-image blob object names on the "index" line when generating patch format output.
+/// --full-index
+pub fn full_index() -> FnOptionArg {
+    optionarg::simple(FULL_INDEX)
+}
+
+/// In addition to --full-index, output a binary diff that can be applied with git-apply.
+/// Implies --patch.
+/// --binary
+pub fn binary() -> FnOptionArg {
+    optionarg::simple(BINARY)
+}
+
+/// Instead of showing the full 40-byte hexadecimal object name in diff-raw format output and diff-tree header lines, show the shortest prefix that is at least <n> hexdigits long that uniquely refers the object.
+/// --abbrev=<n>
+pub fn abbrev(n_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(ABBREV, n_arg)
+}
+
+/// Detect renames.
+/// If n is specified, it is a threshold on the similarity index (i.e. amount of addition/deletions compared to the file’s size).
+/// -M<n>, --find-renames=<n>
+pub fn find_renames(n_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(FIND_RENAMES, n_arg)
+}
+
+/// Detect copies as well as renames.
+/// It has the same meaning as for -M<n>.
+/// -C<n>, --find-copies=<n>
+pub fn find_copies(n_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(FIND_COPIES, n_arg)
+}
+
+/// For performance reasons, by default, -C option finds copies only if the original file of the copy was modified in the same changeset.
+/// This flag makes the command inspect unmodified files as candidates for the source of copy.
+/// --find-copies-harder
+pub fn find_copies_harder() -> FnOptionArg {
+    optionarg::simple(FIND_COPIES_HARDER)
+}
+
+/// Turn off rename detection, even when the configuration file gives the default to do so.
+/// --no-renames
+pub fn no_renames() -> FnOptionArg {
+    optionarg::simple(NO_RENAMES)
+}
+
+/// Select only files that are Added (A), Copied (C), Deleted (D), Modified (M), Renamed (R), have their type changed (T), are Unmerged (U), are Unknown (X), or have had their pairing Broken (B).
+/// Any combination of the filter characters (including none) can be used.
+/// Lowercase letters exclude the matching files.
+/// --diff-filter=<filter>
+pub fn diff_filter(filter_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(DIFF_FILTER, filter_arg)
+}
+
+/// Swap two inputs; that is, show differences from index or on-disk file to tree contents.
+/// -R
+pub fn reverse() -> FnOptionArg {
+    optionarg::simple(REVERSE)
+}
+
+/// When run from a subdirectory of the project, it can be told to exclude changes outside the directory and show pathnames relative to it with this option.
+/// --relative=<path>
+pub fn relative(path_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(RELATIVE, path_arg)
+}
+
+/// Treat all files as text.
+/// -a, --text
+pub fn text() -> FnOptionArg {
+    optionarg::simple(TEXT)
+}
+
+/// Ignore changes in amount of whitespace.
+/// -b, --ignore-space-change
+pub fn ignore_space_change() -> FnOptionArg {
+    optionarg::simple(IGNORE_SPACE_CHANGE)
+}
+
+/// Ignore whitespace when comparing lines.
+/// -w, --ignore-all-space
+pub fn ignore_all_space() -> FnOptionArg {
+    optionarg::simple(IGNORE_ALL_SPACE)
+}
+
+/// Ignore changes whose lines are all blank.
+/// --ignore-blank-lines
+pub fn ignore_blank_lines() -> FnOptionArg {
+    optionarg::simple(IGNORE_BLANK_LINES)
+}
+
+/// Make the program exit with codes similar to diff(1).
+/// That is, it exits with 1 if there were differences and 0 means no differences.
+/// --exit-code
+pub fn exit_code() -> FnOptionArg {
+    optionarg::simple(EXIT_CODE)
+}
+
+/// Disable all output of the program.
+/// Implies --exit-code.
+/// --quiet
+pub fn quiet() -> FnOptionArg {
+    optionarg::simple(QUIET)
+}
+
+/// Turn off colored diff.
+/// --no-color
+pub fn no_color() -> FnOptionArg {
+    optionarg::simple(NO_COLOR)
+}
+
+/// Disallow external diff drivers.
+/// --no-ext-diff
+pub fn no_ext_diff() -> FnOptionArg {
+    optionarg::simple(NO_EXT_DIFF)
+}
+
+/// Disallow external text conversion filters to be used when comparing binary files.
+/// --no-textconv
+pub fn no_textconv() -> FnOptionArg {
+    optionarg::simple(NO_TEXTCONV)
+}
+
+/// Specify how differences in submodules are shown: short, log or diff.
+/// --submodule=<format>
+pub fn submodule(format_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(SUBMODULE, format_arg)
+}
+
+/// Commit, or commit range such as <commit>..<commit>, to compare.
+/// Give it twice to compare two commits.
+/// <commit>
+pub fn commit(commit: &str) -> FnOptionArg {
+    optionarg::value_parameter(commit)
+}
+
+/// Do not interpret any more arguments as options.
+/// --
+pub fn hyphen_hyphen() -> FnOptionArg {
+    optionarg::simple(HYPHEN_HYPHEN)
+}
+
+/// Limits the paths affected by the operation.
+/// <pathspec>
+pub fn pathspec(pathspec: &str) -> FnOptionArg {
+    optionarg::value_parameter(pathspec)
+}
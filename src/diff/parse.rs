@@ -0,0 +1,489 @@
+use crate::optionarg;
+use crate::parser::{is_nul_terminated, parse_error, parse_mode, unquote_path};
+use crate::wrap_command::FnOptionArg;
+use crate::WrapError;
+
+const SRC_PREFIX: &str = "--src-prefix=a/";
+const DST_PREFIX: &str = "--dst-prefix=b/";
+const DEV_NULL: &str = "/dev/null";
+// prefixes of the paths of a patch: a/ and b/ by default, c/ i/ w/ o/ (1/ 2/ without an index) with diff.mnemonicPrefix
+const PATH_PREFIXES: [&str; 8] = ["a/", "b/", "c/", "i/", "w/", "o/", "1/", "2/"];
+
+/// Kind of change of a file, as reported by `--raw` and `--name-status`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiffStatus {
+    Added,
+    Copied,
+    Deleted,
+    Modified,
+    Renamed,
+    TypeChanged,
+    Unmerged,
+    Unknown,
+}
+
+impl DiffStatus {
+    /// Maps a status letter (`A`, `C`, `D`, `M`, `R`, `T`, `U`, `X`) to its status
+    pub fn from_letter(letter: char) -> Option<Self> {
+        match letter {
+            'A' => Some(DiffStatus::Added),
+            'C' => Some(DiffStatus::Copied),
+            'D' => Some(DiffStatus::Deleted),
+            'M' => Some(DiffStatus::Modified),
+            'R' => Some(DiffStatus::Renamed),
+            'T' => Some(DiffStatus::TypeChanged),
+            'U' => Some(DiffStatus::Unmerged),
+            'X' => Some(DiffStatus::Unknown),
+            _ => None,
+        }
+    }
+}
+
+/// A file changed by a diff.
+/// Which fields are filled in depends on the output format parsed:
+/// object names and modes come from `--raw` and patches, line counts from `--numstat` and patches,
+/// hunks from patches only.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileDiff {
+    /// `--numstat` alone does not report a status: files are then Modified, or Renamed if a rename was detected
+    pub status: DiffStatus,
+    /// Similarity percentage of a rename or copy, dissimilarity percentage of a rewrite
+    pub score: Option<u8>,
+    /// Path before the change, None for an added file
+    pub old_path: Option<String>,
+    /// Path after the change, None for a deleted file
+    pub new_path: Option<String>,
+    pub old_mode: Option<u32>,
+    pub new_mode: Option<u32>,
+    pub old_oid: Option<String>,
+    pub new_oid: Option<String>,
+    /// Added and removed lines, None for binary files or when not reported
+    pub added: Option<u32>,
+    pub removed: Option<u32>,
+    pub binary: bool,
+    pub hunks: Vec<Hunk>,
+}
+
+impl FileDiff {
+    fn new(status: DiffStatus) -> Self {
+        Self {
+            status,
+            score: None,
+            old_path: None,
+            new_path: None,
+            old_mode: None,
+            new_mode: None,
+            old_oid: None,
+            new_oid: None,
+            added: None,
+            removed: None,
+            binary: false,
+            hunks: Vec::new(),
+        }
+    }
+
+    /// The path of the file after the change, or before it for a deleted file
+    pub fn path(&self) -> &str {
+        self.new_path.as_deref().or(self.old_path.as_deref()).unwrap_or_default()
+    }
+
+    fn set_paths(&mut self, old: String, new: String) {
+        self.old_path = (self.status != DiffStatus::Added).then_some(old);
+        self.new_path = (self.status != DiffStatus::Deleted).then_some(new);
+    }
+}
+
+/// A hunk of a patch, starting with a `@@ -<old_start>,<old_lines> +<new_start>,<new_lines> @@` line
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Hunk {
+    pub old_start: u32,
+    pub old_lines: u32,
+    pub new_start: u32,
+    pub new_lines: u32,
+    /// Text following the hunk range, usually the enclosing function
+    pub header: String,
+    pub lines: Vec<DiffLine>,
+}
+
+/// A line of a hunk, without its leading ` `, `+` or `-`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DiffLine {
+    Context(String),
+    Added(String),
+    Removed(String),
+}
+
+/// Parses the output of `git diff --raw`, with or without `-z`.
+/// If `--numstat` is given as well, the line counts are filled in.
+pub fn parse_raw(output: &str) -> Result<Vec<FileDiff>, WrapError> {
+    let mut fields = Fields::new(output);
+    let mut diffs = Vec::new();
+    let mut counts = Vec::new();
+    while let Some(field) = fields.next() {
+        if let Some(raw) = field.strip_prefix(':') {
+            diffs.push(parse_raw_entry(raw, &mut fields)?);
+        } else {
+            counts.push(parse_numstat_entry(field, &mut fields)?);
+        }
+    }
+    // --numstat records follow the --raw ones, in the same order
+    if !counts.is_empty() {
+        if counts.len() != diffs.len() {
+            return Err(parse_error("numstat records", output));
+        }
+        for (diff, count) in diffs.iter_mut().zip(counts) {
+            diff.added = count.added;
+            diff.removed = count.removed;
+            diff.binary = count.binary;
+        }
+    }
+    Ok(diffs)
+}
+
+/// Parses the output of `git diff --numstat`, with or without `-z`
+pub fn parse_numstat(output: &str) -> Result<Vec<FileDiff>, WrapError> {
+    let mut fields = Fields::new(output);
+    let mut diffs = Vec::new();
+    while let Some(field) = fields.next() {
+        diffs.push(parse_numstat_entry(field, &mut fields)?);
+    }
+    Ok(diffs)
+}
+
+/// Parses the output of `git diff --name-status`, with or without `-z`
+pub fn parse_name_status(output: &str) -> Result<Vec<FileDiff>, WrapError> {
+    let mut fields = Fields::new(output);
+    let mut diffs = Vec::new();
+    while let Some(field) = fields.next() {
+        let mut diff = status_entry(field)?;
+        read_paths(&mut diff, &mut fields, field)?;
+        diffs.push(diff);
+    }
+    Ok(diffs)
+}
+
+/// The `--src-prefix=a/ --dst-prefix=b/` options, overriding the `diff.mnemonicPrefix` and `diff.noprefix`
+/// settings of the user so the paths of a patch are read back unambiguously by [parse_patch]
+pub fn default_prefixes() -> FnOptionArg {
+    optionarg::double_value_parameter(SRC_PREFIX, DST_PREFIX)
+}
+
+/// Parses a patch, as output by `git diff` or `git diff --patch`, into per-file diffs holding their hunks.
+/// Lines are split on newlines only: a carriage return ending a line of a file is kept in its text.
+/// Paths are read with the default `a/` and `b/` prefixes, mnemonic ones (`diff.mnemonicPrefix`) or none (`diff.noprefix`);
+/// as a path without prefix may itself start like one, run git with [default_prefixes] when the user configuration is unknown.
+pub fn parse_patch(output: &str) -> Result<Vec<FileDiff>, WrapError> {
+    let mut diffs: Vec<FileDiff> = Vec::new();
+    let mut remaining: (u32, u32) = (0, 0);
+    let mut prefixes = ("a/", "b/");
+
+    for line in output.split_terminator('\n') {
+        if let Some(header) = line.strip_prefix("diff --git ") {
+            let mut diff = FileDiff::new(DiffStatus::Modified);
+            let (old, new, found) = split_git_header(header).ok_or_else(|| parse_error("diff header", line))?;
+            prefixes = found;
+            diff.old_path = Some(old);
+            diff.new_path = Some(new);
+            diff.added = Some(0);
+            diff.removed = Some(0);
+            diffs.push(diff);
+            remaining = (0, 0);
+            continue;
+        }
+        let diff = diffs.last_mut().ok_or_else(|| parse_error("patch", line))?;
+
+        if remaining != (0, 0) {
+            let (old, new) = &mut remaining;
+            let hunk = diff.hunks.last_mut().ok_or_else(|| parse_error("patch", line))?;
+            let kind = line.chars().next();
+            let text = String::from(kind.map_or("", |k| &line[k.len_utf8()..]));
+            match kind {
+                Some('+') => {
+                    *new = new.saturating_sub(1);
+                    diff.added = diff.added.map(|n| n + 1);
+                    hunk.lines.push(DiffLine::Added(text));
+                }
+                Some('-') => {
+                    *old = old.saturating_sub(1);
+                    diff.removed = diff.removed.map(|n| n + 1);
+                    hunk.lines.push(DiffLine::Removed(text));
+                }
+                Some('\\') => {}
+                _ => {
+                    *old = old.saturating_sub(1);
+                    *new = new.saturating_sub(1);
+                    hunk.lines.push(DiffLine::Context(text));
+                }
+            }
+            continue;
+        }
+
+        if let Some(range) = line.strip_prefix("@@ -") {
+            let hunk = parse_hunk_header(range).ok_or_else(|| parse_error("hunk header", line))?;
+            remaining = (hunk.old_lines, hunk.new_lines);
+            diff.hunks.push(hunk);
+        } else if let Some(mode) = line.strip_prefix("new file mode ") {
+            diff.status = DiffStatus::Added;
+            diff.old_path = None;
+            diff.new_mode = Some(parse_mode(mode)?);
+        } else if let Some(mode) = line.strip_prefix("deleted file mode ") {
+            diff.status = DiffStatus::Deleted;
+            diff.new_path = None;
+            diff.old_mode = Some(parse_mode(mode)?);
+        } else if let Some(mode) = line.strip_prefix("old mode ") {
+            diff.old_mode = Some(parse_mode(mode)?);
+        } else if let Some(mode) = line.strip_prefix("new mode ") {
+            diff.new_mode = Some(parse_mode(mode)?);
+        } else if let Some(score) = line.strip_prefix("similarity index ").or(line.strip_prefix("dissimilarity index ")) {
+            diff.score = score.trim_end_matches('%').parse().ok();
+        } else if let Some(path) = line.strip_prefix("rename from ").or(line.strip_prefix("copy from ")) {
+            diff.old_path = Some(unquote_path(path));
+        } else if let Some(path) = line.strip_prefix("rename to ") {
+            diff.status = DiffStatus::Renamed;
+            diff.new_path = Some(unquote_path(path));
+        } else if let Some(path) = line.strip_prefix("copy to ") {
+            diff.status = DiffStatus::Copied;
+            diff.new_path = Some(unquote_path(path));
+        } else if let Some(path) = line.strip_prefix("--- ") {
+            if let Some(path) = patch_path(path, prefixes.0) {
+                diff.old_path = Some(path);
+            }
+        } else if let Some(path) = line.strip_prefix("+++ ") {
+            if let Some(path) = patch_path(path, prefixes.1) {
+                diff.new_path = Some(path);
+            }
+        } else if let Some(index) = line.strip_prefix("index ") {
+            let (oids, mode) = index.split_once(' ').unwrap_or((index, ""));
+            let (old, new) = oids.split_once("..").ok_or_else(|| parse_error("index line", line))?;
+            diff.old_oid = Some(String::from(old));
+            diff.new_oid = Some(String::from(new));
+            if !mode.is_empty() {
+                let mode = parse_mode(mode)?;
+                diff.old_mode = Some(mode);
+                diff.new_mode = Some(mode);
+            }
+        } else if line.starts_with("Binary files ") || line == "GIT binary patch" {
+            diff.binary = true;
+            diff.added = None;
+            diff.removed = None;
+        }
+    }
+
+    Ok(diffs)
+}
+
+// Splits output into fields: NUL-terminated records with -z, tab-separated fields of each line otherwise.
+// Paths are unquoted when not NUL-terminated.
+struct Fields<'a> {
+    nul: bool,
+    fields: std::vec::IntoIter<&'a str>,
+}
+
+impl<'a> Fields<'a> {
+    fn new(output: &'a str) -> Self {
+        let nul = is_nul_terminated(output);
+        let fields: Vec<&str> = if nul {
+            let mut fields: Vec<&str> = output.split('\0').collect();
+            if fields.last().is_some_and(|f| f.trim().is_empty()) {
+                fields.pop();
+            }
+            fields
+        } else {
+            output.lines().filter(|l| !l.is_empty()).flat_map(|l| l.split('\t')).collect()
+        };
+        Self { nul, fields: fields.into_iter() }
+    }
+
+    fn path(&mut self, entry: &str) -> Result<String, WrapError> {
+        let path = self.fields.next().ok_or_else(|| parse_error("path", entry))?;
+        Ok(if self.nul { String::from(path) } else { unquote_path(path) })
+    }
+}
+
+impl<'a> Iterator for Fields<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // records following a NUL are newline separated when several formats are combined
+        self.fields.next().map(|f| f.trim_start_matches('\n'))
+    }
+}
+
+// <status letter>[<score>]
+fn status_entry(field: &str) -> Result<FileDiff, WrapError> {
+    let mut chars = field.chars();
+    let status = chars.next()
+        .and_then(DiffStatus::from_letter)
+        .ok_or_else(|| parse_error("diff status", field))?;
+    let mut diff = FileDiff::new(status);
+    let score = chars.as_str();
+    if !score.is_empty() {
+        diff.score = Some(score.parse().map_err(|_| parse_error("diff score", field))?);
+    }
+    Ok(diff)
+}
+
+// one path, or two for renames and copies
+fn read_paths(diff: &mut FileDiff, fields: &mut Fields, entry: &str) -> Result<(), WrapError> {
+    let old = fields.path(entry)?;
+    let new = match diff.status {
+        DiffStatus::Renamed | DiffStatus::Copied => fields.path(entry)?,
+        _ => old.clone(),
+    };
+    diff.set_paths(old, new);
+    Ok(())
+}
+
+// <old mode> <new mode> <old oid> <new oid> <status>[<score>] followed by the paths
+fn parse_raw_entry(raw: &str, fields: &mut Fields) -> Result<FileDiff, WrapError> {
+    let f: Vec<&str> = raw.split(' ').collect();
+    if f.len() != 5 {
+        return Err(parse_error("raw diff entry", raw));
+    }
+    let mut diff = status_entry(f[4])?;
+    let mode = |m| parse_mode(m).map(|m| (m != 0).then_some(m));
+    diff.old_mode = mode(f[0])?;
+    diff.new_mode = mode(f[1])?;
+    diff.old_oid = Some(String::from(f[2]));
+    diff.new_oid = Some(String::from(f[3]));
+    read_paths(&mut diff, fields, raw)?;
+    Ok(diff)
+}
+
+// <added> <removed> <path>; with -z, a rename has an empty path followed by the old and new paths
+fn parse_numstat_entry(field: &str, fields: &mut Fields) -> Result<FileDiff, WrapError> {
+    let (added, removed, path) = if fields.nul {
+        let mut f = field.splitn(3, '\t');
+        match (f.next(), f.next(), f.next()) {
+            (Some(a), Some(r), Some(p)) => (a, r, p),
+            _ => return Err(parse_error("numstat entry", field)),
+        }
+    } else {
+        let removed = fields.next().ok_or_else(|| parse_error("numstat entry", field))?;
+        (field, removed, fields.fields.next().ok_or_else(|| parse_error("numstat entry", field))?)
+    };
+
+    let mut diff = FileDiff::new(DiffStatus::Modified);
+    if added == "-" && removed == "-" {
+        diff.binary = true;
+    } else {
+        diff.added = Some(added.parse().map_err(|_| parse_error("numstat entry", field))?);
+        diff.removed = Some(removed.parse().map_err(|_| parse_error("numstat entry", field))?);
+    }
+
+    let (old, new) = if fields.nul && path.is_empty() {
+        (fields.path(field)?, fields.path(field)?)
+    } else if fields.nul {
+        (String::from(path), String::from(path))
+    } else {
+        split_rename(path)
+    };
+    if old != new {
+        diff.status = DiffStatus::Renamed;
+    }
+    diff.set_paths(old, new);
+    Ok(diff)
+}
+
+// renames are shown as `old => new`, or `common/{old => new}/suffix`
+fn split_rename(path: &str) -> (String, String) {
+    if let (Some(open), Some(close)) = (path.find('{'), path.rfind('}')) {
+        if let Some((old, new)) = path[open + 1..close].split_once(" => ") {
+            let (prefix, suffix) = (&path[..open], &path[close + 1..]);
+            let join = |middle: &str| format!("{prefix}{middle}{suffix}").replace("//", "/");
+            return (join(old), join(new));
+        }
+    }
+    match path.split_once(" => ") {
+        Some((old, new)) => (unquote_path(old), unquote_path(new)),
+        None => (unquote_path(path), unquote_path(path)),
+    }
+}
+
+// <old prefix><old> <new prefix><new>, either path possibly quoted, along with the prefixes found
+fn split_git_header(header: &str) -> Option<(String, String, (&'static str, &'static str))> {
+    if header.starts_with('"') {
+        let end = quoted_end(header)?;
+        return Some(strip_prefixes(unquote_path(&header[..end]), unquote_path(header[end..].trim_start())));
+    }
+    if header.ends_with('"') {
+        let start = header.find(" \"")?;
+        return Some(strip_prefixes(String::from(&header[..start]), unquote_path(&header[start + 1..])));
+    }
+    // unquoted paths may hold spaces: without a rename both halves are the same
+    // (the `---` and `+++` lines, when present, settle the paths of a rename)
+    if header.len() % 2 == 1 && header.is_char_boundary(header.len() / 2) {
+        let half = header.len() / 2;
+        if let Some(new) = header[half..].strip_prefix(' ') {
+            let (old, new, prefixes) = strip_prefixes(String::from(&header[..half]), String::from(new));
+            if old == new {
+                return Some((old, new, prefixes));
+            }
+        }
+    }
+    // otherwise the new path starts at the first prefix other than the old one, or at the first space without prefixes
+    let split = match prefix_of(header) {
+        Some(old) => PATH_PREFIXES.iter()
+            .filter(|p| **p != old)
+            .filter_map(|p| header.find(format!(" {p}").as_str()))
+            .min()?,
+        None => header.find(' ')?,
+    };
+    Some(strip_prefixes(String::from(&header[..split]), String::from(&header[split + 1..])))
+}
+
+// removes the prefixes of both paths, unless they do not make a pair of distinct prefixes (no prefix at all)
+fn strip_prefixes(old: String, new: String) -> (String, String, (&'static str, &'static str)) {
+    match (prefix_of(&old), prefix_of(&new)) {
+        (Some(o), Some(n)) if o != n => (String::from(&old[o.len()..]), String::from(&new[n.len()..]), (o, n)),
+        _ => (old, new, ("", "")),
+    }
+}
+
+fn prefix_of(path: &str) -> Option<&'static str> {
+    PATH_PREFIXES.iter().find(|p| path.starts_with(**p)).copied()
+}
+
+// path of a `---` or `+++` line, None for /dev/null. Git ends the line with a tab when the path holds a space.
+fn patch_path(path: &str, prefix: &str) -> Option<String> {
+    let path = unquote_path(path.strip_suffix('\t').unwrap_or(path));
+    if path == DEV_NULL {
+        return None;
+    }
+    path.strip_prefix(prefix).map(String::from)
+}
+
+// end of the leading quoted string, the closing quote included
+fn quoted_end(text: &str) -> Option<usize> {
+    let mut escaped = false;
+    for (i, c) in text.char_indices().skip(1) {
+        match c {
+            '\\' if !escaped => escaped = true,
+            '"' if !escaped => return Some(i + 1),
+            _ => escaped = false,
+        }
+    }
+    None
+}
+
+// <old_start>[,<old_lines>] +<new_start>[,<new_lines>] @@[ <header>]
+fn parse_hunk_header(range: &str) -> Option<Hunk> {
+    let (ranges, header) = range.split_once(" @@")?;
+    let (old, new) = ranges.split_once(" +")?;
+    let bounds = |r: &str| -> Option<(u32, u32)> {
+        match r.split_once(',') {
+            Some((start, lines)) => Some((start.parse().ok()?, lines.parse().ok()?)),
+            None => Some((r.parse().ok()?, 1)),
+        }
+    };
+    let (old_start, old_lines) = bounds(old)?;
+    let (new_start, new_lines) = bounds(new)?;
+    Some(Hunk {
+        old_start,
+        old_lines,
+        new_start,
+        new_lines,
+        header: String::from(header.trim_start()),
+        lines: Vec::new(),
+    })
+}
@@ -1,18 +1,132 @@
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
+use crate::wrap_command::WrapOutput;
+use crate::GitVersion;
+
+const MSG_INDEX_LOCKED: &[&str] = &["index.lock': File exists"];
+const MSG_AUTHENTICATION_FAILED: &[&str] = &[
+    "Authentication failed",
+    "Permission denied (publickey",
+    "could not read Username",
+    "could not read Password",
+    "terminal prompts disabled",
+    "HTTP Basic: Access denied",
+    "The requested URL returned error: 403",
+];
+const MSG_NOT_A_REPOSITORY: &[&str] = &["not a git repository"];
+const MSG_NON_FAST_FORWARD: &[&str] = &["(non-fast-forward)", "(fetch first)", "Updates were rejected because"];
+const MSG_DIRTY_WORKTREE: &[&str] = &[
+    "Your local changes to the following files would be overwritten",
+    "untracked working tree files would be overwritten",
+    "Please commit your changes or stash them",
+];
+const MSG_MERGE_CONFLICT: &[&str] = &[
+    "CONFLICT (",
+    "Automatic merge failed",
+    "you need to resolve your current index first",
+    "because you have unmerged files",
+    "Resolve all conflicts manually",
+];
+// conflict reports merge, cherry-pick and revert print to stdout, always at the start of a line
+const MSG_MERGE_CONFLICT_STDOUT: &[&str] = &["CONFLICT (", "Automatic merge failed"];
+const MSG_PATHSPEC_NOT_MATCHED: &[&str] = &["did not match any file(s) known to git", "did not match any files"];
+const MSG_UNKNOWN_REVISION: &[&str] = &[
+    "unknown revision or path not in the working tree",
+    "Needed a single revision",
+    "bad revision",
+    "invalid reference:",
+    "not a valid object name",
+    "not a valid ref",
+];
 
 pub enum WrapError {
     FailedExecuteProcess(String),
-    ExitStatus(String, i32),
+    /// A git failure not matching any of the typed variants below
+    ExitStatus(WrapOutput),
+    Timeout(String),
+    Cancelled(String),
+    UnsupportedVersion(String, GitVersion, GitVersion),
+    NotARepository(WrapOutput),
+    MergeConflict(WrapOutput),
+    NonFastForward(WrapOutput),
+    AuthenticationFailed(WrapOutput),
+    IndexLocked(WrapOutput),
+    UnknownRevision(WrapOutput),
+    PathspecNotMatched(WrapOutput),
+    DirtyWorktree(WrapOutput),
+    ParseOutput(String),
+    /// An argument the call cannot run with, rejected before running git
+    InvalidArgument(String),
 }
 
 impl Error for WrapError {}
 
 impl WrapError {
+    /// Maps the output of a failed git command to the typed variant matching its failure message,
+    /// falling back to [WrapError::ExitStatus].
+    /// Messages are looked for on stderr, stdout being payload (a patch, a log...) that may quote them;
+    /// only the conflict reports git prints on stdout are read from it, at the start of a line.
+    pub fn classify(output: WrapOutput) -> Self {
+        let matches = |messages: &[&str]| messages.iter().any(|m| output.stderr.contains(m));
+        let conflict_reported = output.stdout.lines()
+            .any(|l| MSG_MERGE_CONFLICT_STDOUT.iter().any(|m| l.starts_with(m)));
+        if matches(MSG_INDEX_LOCKED) {
+            WrapError::IndexLocked(output)
+        } else if matches(MSG_AUTHENTICATION_FAILED) {
+            WrapError::AuthenticationFailed(output)
+        } else if matches(MSG_NOT_A_REPOSITORY) {
+            WrapError::NotARepository(output)
+        } else if matches(MSG_NON_FAST_FORWARD) {
+            WrapError::NonFastForward(output)
+        } else if matches(MSG_DIRTY_WORKTREE) {
+            WrapError::DirtyWorktree(output)
+        } else if matches(MSG_MERGE_CONFLICT) || conflict_reported {
+            WrapError::MergeConflict(output)
+        } else if matches(MSG_PATHSPEC_NOT_MATCHED) {
+            WrapError::PathspecNotMatched(output)
+        } else if matches(MSG_UNKNOWN_REVISION) {
+            WrapError::UnknownRevision(output)
+        } else {
+            WrapError::ExitStatus(output)
+        }
+    }
+
+    /// Returns the structured output of the failed git command, if it ran to completion
+    pub fn output(&self) -> Option<&WrapOutput> {
+        match self {
+            WrapError::ExitStatus(o) |
+            WrapError::NotARepository(o) |
+            WrapError::MergeConflict(o) |
+            WrapError::NonFastForward(o) |
+            WrapError::AuthenticationFailed(o) |
+            WrapError::IndexLocked(o) |
+            WrapError::UnknownRevision(o) |
+            WrapError::PathspecNotMatched(o) |
+            WrapError::DirtyWorktree(o) => Some(o),
+            _ => None,
+        }
+    }
+
     fn format(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             WrapError::FailedExecuteProcess(s) => write!(f, "failed to execute process: {}", s),
-            WrapError::ExitStatus(o, x) => write!(f, "exit status: {}: {}", x, o),
+            WrapError::ExitStatus(o) => match o.code {
+                Some(x) => write!(f, "exit status: {}: {}", x, o.combined()),
+                None => write!(f, "terminated by signal: {}", o.combined()),
+            },
+            WrapError::Timeout(s) => write!(f, "timed out: {}", s),
+            WrapError::Cancelled(s) => write!(f, "cancelled: {}", s),
+            WrapError::UnsupportedVersion(o, r, x) => write!(f, "unsupported git version: {} requires git {}, found {}", o, r, x),
+            WrapError::NotARepository(o) => write!(f, "not a git repository: {}", o.combined()),
+            WrapError::MergeConflict(o) => write!(f, "merge conflict: {}", o.combined()),
+            WrapError::NonFastForward(o) => write!(f, "non-fast-forward update rejected: {}", o.combined()),
+            WrapError::AuthenticationFailed(o) => write!(f, "authentication failed: {}", o.combined()),
+            WrapError::IndexLocked(o) => write!(f, "index is locked: {}", o.combined()),
+            WrapError::UnknownRevision(o) => write!(f, "unknown revision: {}", o.combined()),
+            WrapError::PathspecNotMatched(o) => write!(f, "pathspec did not match: {}", o.combined()),
+            WrapError::DirtyWorktree(o) => write!(f, "local changes would be overwritten: {}", o.combined()),
+            WrapError::ParseOutput(s) => write!(f, "failed to parse git output: {}", s),
+            WrapError::InvalidArgument(s) => write!(f, "invalid argument: {}", s),
         }
     }
 }
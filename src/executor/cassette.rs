@@ -0,0 +1,138 @@
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use serde::{Deserialize, Serialize};
+use crate::executor::{Executor, ProcessExecutor};
+use crate::wrap_command::{WrapCommand, WrapOutput};
+use crate::WrapError;
+
+/// A git command execution, as stored in a cassette file
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub program: String,
+    pub args: Vec<String>,
+    pub current_dir: Option<String>,
+    /// Environment overrides, None standing for a removed variable
+    pub env: BTreeMap<String, Option<String>>,
+    pub code: Option<i32>,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl Interaction {
+    fn request(command: &WrapCommand) -> Self {
+        let spec = command.dry_run_args();
+        let lossy = |s: &OsString| s.to_string_lossy().into_owned();
+        Self {
+            program: spec.argv.first().map(lossy).unwrap_or_default(),
+            args: spec.argv.iter().skip(1).map(lossy).collect(),
+            current_dir: spec.current_dir.map(|p| p.to_string_lossy().into_owned()),
+            env: spec.env.iter().map(|(k, v)| (lossy(k), v.as_ref().map(lossy))).collect(),
+            code: None,
+            stdout: String::new(),
+            stderr: String::new(),
+        }
+    }
+
+    fn same_request(&self, other: &Interaction) -> bool {
+        self.program == other.program
+            && self.args == other.args
+            && self.current_dir == other.current_dir
+            && self.env == other.env
+    }
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct Cassette {
+    interactions: Vec<Interaction>,
+}
+
+enum Mode {
+    Record(Arc<dyn Executor>),
+    Replay,
+}
+
+/// An executor recording git sessions to a JSON cassette file, or replaying them without spawning git.
+/// In replay mode, commands must be issued in the recorded order, with the same arguments,
+/// working directory and environment overrides; any other command fails.
+pub struct CassetteExecutor {
+    path: PathBuf,
+    mode: Mode,
+    cassette: Mutex<(Cassette, usize)>,
+}
+
+impl CassetteExecutor {
+    /// Runs git as a child process, recording every execution to the cassette file at [path].
+    /// The file is rewritten after each execution.
+    pub fn record(path: &str) -> Self {
+        Self::record_with(path, Arc::new(ProcessExecutor))
+    }
+
+    /// Records every execution run by [executor] to the cassette file at [path]
+    pub fn record_with(path: &str, executor: Arc<dyn Executor>) -> Self {
+        Self {
+            path: PathBuf::from(path),
+            mode: Mode::Record(executor),
+            cassette: Mutex::new((Cassette::default(), 0)),
+        }
+    }
+
+    /// Replays the executions stored in the cassette file at [path]
+    pub fn replay(path: &str) -> io::Result<Self> {
+        let cassette: Cassette = serde_json::from_str(fs::read_to_string(path)?.as_str())?;
+        Ok(Self {
+            path: PathBuf::from(path),
+            mode: Mode::Replay,
+            cassette: Mutex::new((cassette, 0)),
+        })
+    }
+
+    /// Returns the number of recorded executions, or of executions still to be replayed
+    pub fn remaining(&self) -> usize {
+        let (cassette, next) = &*self.cassette.lock().unwrap();
+        match self.mode {
+            Mode::Record(_) => cassette.interactions.len(),
+            Mode::Replay => cassette.interactions.len() - next,
+        }
+    }
+
+    fn unexpected(&self, request: &Interaction, expected: Option<&Interaction>) -> WrapError {
+        WrapError::FailedExecuteProcess(format!(
+            "cassette {}: unexpected command {:?}, expected {:?}",
+            self.path.display(),
+            request,
+            expected
+        ))
+    }
+}
+
+impl Executor for CassetteExecutor {
+    fn execute(&self, command: &WrapCommand) -> Result<WrapOutput, WrapError> {
+        let mut request = Interaction::request(command);
+        let (cassette, next) = &mut *self.cassette.lock().unwrap();
+        match &self.mode {
+            Mode::Record(executor) => {
+                let output = executor.execute(command)?;
+                request.code = output.code;
+                request.stdout = output.stdout.clone();
+                request.stderr = output.stderr.clone();
+                cassette.interactions.push(request);
+                let json = serde_json::to_string_pretty(&*cassette).map_err(|e| WrapError::FailedExecuteProcess(e.to_string()))?;
+                fs::write(&self.path, json).map_err(|e| {
+                    WrapError::FailedExecuteProcess(format!("cassette {}: {}", self.path.display(), e))
+                })?;
+                Ok(output)
+            }
+            Mode::Replay => match cassette.interactions.get(*next) {
+                Some(i) if i.same_request(&request) => {
+                    *next += 1;
+                    Ok(WrapOutput::new(i.code, i.stdout.clone().into_bytes(), i.stderr.clone().into_bytes()))
+                }
+                expected => Err(self.unexpected(&request, expected)),
+            },
+        }
+    }
+}
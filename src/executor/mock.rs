@@ -0,0 +1,48 @@
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use crate::executor::Executor;
+use crate::wrap_command::{WrapCommand, WrapOutput};
+use crate::WrapError;
+
+/// A scripted executor for tests: commands are matched on the command line produced by [WrapCommand::dry_run],
+/// and answered with canned outputs without spawning git.
+#[derive(Default)]
+pub struct MockExecutor {
+    expected: Mutex<VecDeque<(String, WrapOutput)>>,
+    executed: Mutex<Vec<String>>,
+}
+
+impl MockExecutor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the answer to the next execution of the [command_line].
+    /// Each expectation answers a single execution; expectations for the same command line are used in order.
+    pub fn expect(&self, command_line: &str, code: i32, stdout: &str, stderr: &str) {
+        let output = WrapOutput::new(Some(code), stdout.as_bytes().to_vec(), stderr.as_bytes().to_vec());
+        self.expected.lock().unwrap().push_back((String::from(command_line), output));
+    }
+
+    /// Returns the command lines executed so far, in order
+    pub fn executed(&self) -> Vec<String> {
+        self.executed.lock().unwrap().clone()
+    }
+
+    /// Returns the command lines whose expectations were not used yet
+    pub fn pending(&self) -> Vec<String> {
+        self.expected.lock().unwrap().iter().map(|(c, _)| c.clone()).collect()
+    }
+}
+
+impl Executor for MockExecutor {
+    fn execute(&self, command: &WrapCommand) -> Result<WrapOutput, WrapError> {
+        let command_line = command.dry_run()?;
+        self.executed.lock().unwrap().push(command_line.clone());
+        let mut expected = self.expected.lock().unwrap();
+        match expected.iter().position(|(c, _)| *c == command_line) {
+            Some(i) => Ok(expected.remove(i).unwrap().1),
+            None => Err(WrapError::FailedExecuteProcess(format!("unexpected command: {command_line}"))),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use std::cell::RefCell;
+use std::sync::{Arc, OnceLock};
+use crate::wrap_command::{WrapCommand, WrapOutput};
+use crate::WrapError;
+
+mod mock;
+pub use mock::*;
+
+mod cassette;
+pub use cassette::*;
+
+/// Runs git commands on behalf of [WrapCommand].
+/// The executor reports the output of the command whatever its exit code:
+/// turning a non-zero exit code into an error is left to [WrapCommand].
+pub trait Executor: Send + Sync {
+    fn execute(&self, command: &WrapCommand) -> Result<WrapOutput, WrapError>;
+}
+
+/// The default executor, running git as a child process
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ProcessExecutor;
+
+impl Executor for ProcessExecutor {
+    fn execute(&self, command: &WrapCommand) -> Result<WrapOutput, WrapError> {
+        command.execute_process()
+    }
+}
+
+thread_local! {
+    static THREAD_EXECUTOR: RefCell<Option<Arc<dyn Executor>>> = const { RefCell::new(None) };
+}
+
+/// Sets the executor given to the commands created from now on by the current thread.
+/// None restores the [ProcessExecutor].
+pub fn set_thread_executor(executor: Option<Arc<dyn Executor>>) {
+    THREAD_EXECUTOR.with(|e| e.replace(executor));
+}
+
+/// Returns the executor given to the commands created by the current thread
+pub fn thread_executor() -> Arc<dyn Executor> {
+    // a single process executor is shared: versions are cached per executor, each binary is then queried once
+    static PROCESS_EXECUTOR: OnceLock<Arc<dyn Executor>> = OnceLock::new();
+    THREAD_EXECUTOR.with(|e| e.borrow().clone())
+        .unwrap_or_else(|| PROCESS_EXECUTOR.get_or_init(|| Arc::new(ProcessExecutor)).clone())
+}
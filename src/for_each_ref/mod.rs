@@ -0,0 +1,17 @@
+use crate::wrap_command::WrapCommand;
+use crate::git;
+
+mod options;
+pub use options::*;
+
+mod parse;
+pub use parse::*;
+
+pub const GIT_COMMAND: &str = "for-each-ref";
+
+/// Output information on each ref.
+/// Iterate over all refs that match <pattern> and show them according to the given <format>, after sorting them according to the given set of <key>.
+/// [Git doc](https://git-scm.com/docs/git-for-each-ref)
+pub fn for_each_ref(current_dir: Option<&str>) -> WrapCommand {
+    git(GIT_COMMAND, current_dir)
+}
@@ -0,0 +1,106 @@
+// Warning!! Code generated automatically: this file must not be edited by hand
+use crate::optionarg;
+use crate::wrap_command::FnOptionArg;
+
+pub const COUNT: &str = "--count";
+pub const SORT: &str = "--sort";
+pub const FORMAT: &str = "--format";
+pub const COLOR: &str = "--color";
+pub const SHELL: &str = "--shell";
+pub const POINTS_AT: &str = "--points-at";
+pub const MERGED: &str = "--merged";
+pub const NO_MERGED: &str = "--no-merged";
+pub const CONTAINS: &str = "--contains";
+pub const NO_CONTAINS: &str = "--no-contains";
+pub const IGNORE_CASE: &str = "--ignore-case";
+pub const OMIT_EMPTY: &str = "--omit-empty";
+pub const EXCLUDE: &str = "--exclude";
+
+/// By default the command shows all refs that match <pattern>.
+/// This option makes it stop after showing that many refs.
+/// --count=<count>
+pub fn count(count_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(COUNT, count_arg)
+}
+
+/// A field name to sort on.
+/// Prefix - to sort in descending order of the value.
+/// When unspecified, refname is used.
+/// You may use the --sort=<key> option multiple times, in which case the last key becomes the primary key.
+/// --sort=<key>
+pub fn sort(key_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(SORT, key_arg)
+}
+
+/// A string that interpolates %(fieldname) from a ref being shown and the object it points at.
+/// %xx where xx are hex digits interpolates to character with hex code xx.
+/// --format=<format>
+pub fn format(format_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(FORMAT, format_arg)
+}
+
+/// Respect any colors specified in the --format option.
+/// --color=<when>
+pub fn color(when_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(COLOR, when_arg)
+}
+
+/// If given, strings that substitute %(fieldname) placeholders are quoted as string literals suitable for the specified host language.
+/// -s, --shell
+pub fn shell() -> FnOptionArg {
+    optionarg::simple(SHELL)
+}
+
+/// Only list refs which points at the given object.
+/// --points-at=<object>
+pub fn points_at(object_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(POINTS_AT, object_arg)
+}
+
+/// Only list refs whose tips are reachable from the specified commit (HEAD if not specified).
+/// --merged=<object>
+pub fn merged(object_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(MERGED, object_arg)
+}
+
+/// Only list refs whose tips are not reachable from the specified commit (HEAD if not specified).
+/// --no-merged=<object>
+pub fn no_merged(object_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(NO_MERGED, object_arg)
+}
+
+/// Only list refs which contain the specified commit (HEAD if not specified).
+/// --contains=<object>
+pub fn contains(object_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(CONTAINS, object_arg)
+}
+
+/// Only list refs which don’t contain the specified commit (HEAD if not specified).
+/// --no-contains=<object>
+pub fn no_contains(object_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(NO_CONTAINS, object_arg)
+}
+
+/// Sorting and filtering refs are case insensitive.
+/// --ignore-case
+pub fn ignore_case() -> FnOptionArg {
+    optionarg::simple(IGNORE_CASE)
+}
+
+/// Do not print a newline after formatted refs where the format expands to the empty string.
+/// --omit-empty
+pub fn omit_empty() -> FnOptionArg {
+    optionarg::simple(OMIT_EMPTY)
+}
+
+/// If one or more patterns are given, only refs which do not match any excluded pattern(s) are shown.
+/// --exclude=<pattern>
+pub fn exclude(pattern_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(EXCLUDE, pattern_arg)
+}
+
+/// If one or more patterns are given, only refs are shown that match against at least one pattern, either using fnmatch(3) or literally, in the latter case matching completely or from the beginning up to a slash.
+/// <pattern>
+pub fn pattern(pattern: &str) -> FnOptionArg {
+    optionarg::value_parameter(pattern)
+}
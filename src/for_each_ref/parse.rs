@@ -0,0 +1,185 @@
+use crate::for_each_ref::format;
+use crate::parser::parse_error;
+use crate::wrap_command::FnOptionArg;
+use crate::WrapError;
+
+const RECORD_SEPARATOR: char = '\x1e';
+const FIELD_SEPARATOR: char = '\0';
+// prefixes the tracking info of refs having an upstream, telling them apart from refs without one
+const UPSTREAM_MARKER: &str = "=";
+
+/// A field of a ref that can be requested from [RefFormat].
+/// The full refname is always included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RefAtom {
+    /// `%(refname:short)`, e.g. `main` for `refs/heads/main`
+    ShortName,
+    /// `%(objectname)`
+    ObjectName,
+    /// `%(objecttype)`: commit, tag, tree or blob
+    ObjectType,
+    /// `%(HEAD)`: whether the ref is the checked out branch
+    Head,
+    /// `%(symref)`: the ref a symbolic ref points to
+    Symref,
+    /// `%(upstream)`: the ref the branch tracks
+    Upstream,
+    /// `%(upstream:track)`: commits ahead and behind the upstream
+    UpstreamTrack,
+    /// `%(push)`: the ref the branch pushes to
+    Push,
+    /// `%(authorname)`
+    AuthorName,
+    /// `%(authoremail)`
+    AuthorEmail,
+    /// `%(authordate:unix)`
+    AuthorDate,
+    /// `%(committerdate:unix)`
+    CommitterDate,
+    /// `%(subject)`
+    Subject,
+}
+
+impl RefAtom {
+    fn placeholder(&self) -> &'static str {
+        match self {
+            RefAtom::ShortName => "%(refname:short)",
+            RefAtom::ObjectName => "%(objectname)",
+            RefAtom::ObjectType => "%(objecttype)",
+            RefAtom::Head => "%(HEAD)",
+            RefAtom::Symref => "%(symref)",
+            RefAtom::Upstream => "%(upstream)",
+            RefAtom::UpstreamTrack => "%(if)%(upstream)%(then)=%(upstream:track,nobracket)%(end)",
+            RefAtom::Push => "%(push)",
+            RefAtom::AuthorName => "%(authorname)",
+            RefAtom::AuthorEmail => "%(authoremail)",
+            RefAtom::AuthorDate => "%(authordate:unix)",
+            RefAtom::CommitterDate => "%(committerdate:unix)",
+            RefAtom::Subject => "%(subject)",
+        }
+    }
+}
+
+/// A ref, as listed by `git for-each-ref` with a [RefFormat].
+/// Fields whose atom was not requested, or which are empty for the ref, are None.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RefInfo {
+    /// Full refname, e.g. `refs/heads/main`
+    pub refname: String,
+    pub short_name: Option<String>,
+    pub object_name: Option<String>,
+    pub object_type: Option<String>,
+    /// True if the ref is the checked out branch
+    pub head: bool,
+    pub symref: Option<String>,
+    pub upstream: Option<String>,
+    /// None if the branch has no upstream or [RefAtom::UpstreamTrack] was not requested
+    pub track: Option<Tracking>,
+    pub push: Option<String>,
+    pub author_name: Option<String>,
+    /// Author email, without angle brackets
+    pub author_email: Option<String>,
+    /// Seconds since the UNIX epoch
+    pub author_date: Option<i64>,
+    /// Seconds since the UNIX epoch
+    pub committer_date: Option<i64>,
+    pub subject: Option<String>,
+}
+
+/// How a branch compares to its upstream
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Tracking {
+    /// Commits of the branch missing from the upstream
+    pub ahead: u32,
+    /// Commits of the upstream missing from the branch
+    pub behind: u32,
+    /// True if the upstream is configured but no longer exists
+    pub gone: bool,
+}
+
+/// A `--format` built from a list of atoms, whose output is parsed into [RefInfo] records.
+/// Fields are NUL-separated, so values holding spaces or tabs are read back as is.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RefFormat {
+    atoms: Vec<RefAtom>,
+}
+
+impl RefFormat {
+    pub fn new(atoms: &[RefAtom]) -> Self {
+        Self { atoms: atoms.to_vec() }
+    }
+
+    /// The format string, e.g. `%1e%(refname)%00%(objectname)` for [RefAtom::ObjectName]
+    pub fn format_string(&self) -> String {
+        let mut fmt = String::from("%1e%(refname)");
+        for atom in &self.atoms {
+            fmt.push_str("%00");
+            fmt.push_str(atom.placeholder());
+        }
+        fmt
+    }
+
+    /// The `--format` option reading the requested atoms
+    pub fn option(&self) -> FnOptionArg {
+        format(self.format_string().as_str())
+    }
+
+    /// Parses the output of `git for-each-ref` run with [RefFormat::option]
+    pub fn parse(&self, output: &str) -> Result<Vec<RefInfo>, WrapError> {
+        output.split(RECORD_SEPARATOR)
+            .filter(|r| !r.trim().is_empty())
+            .map(|r| self.parse_ref(r.strip_suffix('\n').unwrap_or(r)))
+            .collect()
+    }
+
+    fn parse_ref(&self, record: &str) -> Result<RefInfo, WrapError> {
+        let fields: Vec<&str> = record.split(FIELD_SEPARATOR).collect();
+        if fields.len() != self.atoms.len() + 1 {
+            return Err(parse_error("ref record", record));
+        }
+        let mut info = RefInfo { refname: String::from(fields[0]), ..RefInfo::default() };
+        for (atom, value) in self.atoms.iter().zip(&fields[1..]) {
+            let text = (!value.is_empty()).then(|| String::from(*value));
+            match atom {
+                RefAtom::ShortName => info.short_name = text,
+                RefAtom::ObjectName => info.object_name = text,
+                RefAtom::ObjectType => info.object_type = text,
+                RefAtom::Head => info.head = value.trim() == "*",
+                RefAtom::Symref => info.symref = text,
+                RefAtom::Upstream => info.upstream = text,
+                RefAtom::UpstreamTrack => info.track = value.strip_prefix(UPSTREAM_MARKER)
+                    .map(parse_tracking)
+                    .transpose()?,
+                RefAtom::Push => info.push = text,
+                RefAtom::AuthorName => info.author_name = text,
+                RefAtom::AuthorEmail => info.author_email = text
+                    .map(|e| String::from(e.trim_start_matches('<').trim_end_matches('>'))),
+                RefAtom::AuthorDate => info.author_date = parse_time(value)?,
+                RefAtom::CommitterDate => info.committer_date = parse_time(value)?,
+                RefAtom::Subject => info.subject = text,
+            }
+        }
+        Ok(info)
+    }
+}
+
+// `ahead 1, behind 2`, `ahead 1`, `gone` or empty when in sync
+fn parse_tracking(track: &str) -> Result<Tracking, WrapError> {
+    let mut tracking = Tracking::default();
+    for item in track.split(", ").filter(|i| !i.is_empty()) {
+        match item.split_once(' ') {
+            Some(("ahead", n)) => tracking.ahead = n.parse().map_err(|_| parse_error("upstream track", track))?,
+            Some(("behind", n)) => tracking.behind = n.parse().map_err(|_| parse_error("upstream track", track))?,
+            None if item == "gone" => tracking.gone = true,
+            _ => return Err(parse_error("upstream track", track)),
+        }
+    }
+    Ok(tracking)
+}
+
+fn parse_time(value: &str) -> Result<Option<i64>, WrapError> {
+    if value.is_empty() {
+        return Ok(None);
+    }
+    value.parse().map(Some).map_err(|_| parse_error("ref date", value))
+}
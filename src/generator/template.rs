@@ -81,9 +81,10 @@ pub fn {{ command_name }}(current_dir: Option<&str>) -> WrapCommand {
     (
         TEMPLATE_GIT_COMMAND_FILE,
         r#"use crate::wrap_command::WrapCommand;
+use crate::git_binary;
 
 pub fn git(cmd: &str, current_dir: Option<&str>) -> WrapCommand {
-    let mut command = WrapCommand::new("git", current_dir);
+    let mut command = WrapCommand::new(git_binary().as_str(), current_dir);
     let l_cmd = String::from(cmd);
     command.option(Box::new(move |c: &mut  std::process::Command| { c.arg(l_cmd.as_str()); }));
     command
@@ -94,6 +95,20 @@ pub fn git(cmd: &str, current_dir: Option<&str>) -> WrapCommand {
         TEMPLATE_GIT_COMMAND_MACRO,
         r#"#[macro_export]
 macro_rules! {{ command_name }} {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git({{ command_name }}::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
     ($path:expr,
      $($options:expr), *) => {
         {
@@ -1,7 +1,8 @@
 use crate::wrap_command::WrapCommand;
+use crate::git_binary;
 
 pub fn git(cmd: &str, current_dir: Option<&str>) -> WrapCommand {
-    let mut command = WrapCommand::new("git", current_dir);
+    let mut command = WrapCommand::new(git_binary().as_str(), current_dir);
     let l_cmd = String::from(cmd);
     command.option(Box::new(move |c: &mut  std::process::Command| { c.arg(l_cmd.as_str()); }));
     command
@@ -9,6 +10,20 @@ pub fn git(cmd: &str, current_dir: Option<&str>) -> WrapCommand {
 
 #[macro_export]
 macro_rules! pull {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(pull::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
     ($path:expr,
      $($options:expr), *) => {
         {
@@ -23,6 +38,20 @@ macro_rules! pull {
 
 #[macro_export]
 macro_rules! fetch {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(fetch::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
     ($path:expr,
      $($options:expr), *) => {
         {
@@ -37,6 +66,20 @@ macro_rules! fetch {
 
 #[macro_export]
 macro_rules! init {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(init::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
     ($path:expr,
      $($options:expr), *) => {
         {
@@ -51,6 +94,20 @@ macro_rules! init {
 
 #[macro_export]
 macro_rules! rebase {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(rebase::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
     ($path:expr,
      $($options:expr), *) => {
         {
@@ -65,6 +122,20 @@ macro_rules! rebase {
 
 #[macro_export]
 macro_rules! push {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(push::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
     ($path:expr,
      $($options:expr), *) => {
         {
@@ -79,6 +150,20 @@ macro_rules! push {
 
 #[macro_export]
 macro_rules! clone {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(clone::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
     ($path:expr,
      $($options:expr), *) => {
         {
@@ -93,6 +178,20 @@ macro_rules! clone {
 
 #[macro_export]
 macro_rules! checkout {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(checkout::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
     ($path:expr,
      $($options:expr), *) => {
         {
@@ -107,6 +206,20 @@ macro_rules! checkout {
 
 #[macro_export]
 macro_rules! config {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(config::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
     ($path:expr,
      $($options:expr), *) => {
         {
@@ -121,6 +234,20 @@ macro_rules! config {
 
 #[macro_export]
 macro_rules! reset {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(reset::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
     ($path:expr,
      $($options:expr), *) => {
         {
@@ -135,6 +262,20 @@ macro_rules! reset {
 
 #[macro_export]
 macro_rules! commit {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(commit::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
     ($path:expr,
      $($options:expr), *) => {
         {
@@ -149,6 +290,20 @@ macro_rules! commit {
 
 #[macro_export]
 macro_rules! add {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(add::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
     ($path:expr,
      $($options:expr), *) => {
         {
@@ -163,6 +318,20 @@ macro_rules! add {
 
 #[macro_export]
 macro_rules! merge {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(merge::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
     ($path:expr,
      $($options:expr), *) => {
         {
@@ -177,6 +346,20 @@ macro_rules! merge {
 
 #[macro_export]
 macro_rules! rev_parse {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(rev_parse::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
     ($path:expr,
      $($options:expr), *) => {
         {
@@ -191,6 +374,20 @@ macro_rules! rev_parse {
 
 #[macro_export]
 macro_rules! tag {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(tag::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
     ($path:expr,
      $($options:expr), *) => {
         {
@@ -205,6 +402,20 @@ macro_rules! tag {
 
 #[macro_export]
 macro_rules! status {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(status::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
     ($path:expr,
      $($options:expr), *) => {
         {
@@ -219,6 +430,20 @@ macro_rules! status {
 
 #[macro_export]
 macro_rules! notes {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(notes::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
     ($path:expr,
      $($options:expr), *) => {
         {
@@ -233,6 +458,20 @@ macro_rules! notes {
 
 #[macro_export]
 macro_rules! ls_files {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(ls_files::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
     ($path:expr,
      $($options:expr), *) => {
         {
@@ -247,6 +486,20 @@ macro_rules! ls_files {
 
 #[macro_export]
 macro_rules! branch {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(branch::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
     ($path:expr,
      $($options:expr), *) => {
         {
@@ -261,6 +514,20 @@ macro_rules! branch {
 
 #[macro_export]
 macro_rules! clean {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(clean::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
     ($path:expr,
      $($options:expr), *) => {
         {
@@ -272,3 +539,367 @@ macro_rules! clean {
         }
      }
 }
+
+#[macro_export]
+macro_rules! log {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(log::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
+    ($path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(log::GIT_COMMAND, $path);
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     }
+}
+
+#[macro_export]
+macro_rules! diff {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(diff::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
+    ($path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(diff::GIT_COMMAND, $path);
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     }
+}
+
+#[macro_export]
+macro_rules! stash {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(stash::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
+    ($path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(stash::GIT_COMMAND, $path);
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     }
+}
+
+#[macro_export]
+macro_rules! worktree {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(worktree::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
+    ($path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(worktree::GIT_COMMAND, $path);
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     }
+}
+
+#[macro_export]
+macro_rules! submodule {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(submodule::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
+    ($path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(submodule::GIT_COMMAND, $path);
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     }
+}
+
+#[macro_export]
+macro_rules! remote {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(remote::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
+    ($path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(remote::GIT_COMMAND, $path);
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     }
+}
+
+#[macro_export]
+macro_rules! cherry_pick {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(cherry_pick::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
+    ($path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(cherry_pick::GIT_COMMAND, $path);
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     }
+}
+
+#[macro_export]
+macro_rules! revert {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(revert::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
+    ($path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(revert::GIT_COMMAND, $path);
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     }
+}
+
+#[macro_export]
+macro_rules! bisect {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(bisect::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
+    ($path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(bisect::GIT_COMMAND, $path);
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     }
+}
+
+#[macro_export]
+macro_rules! blame {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(blame::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
+    ($path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(blame::GIT_COMMAND, $path);
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     }
+}
+
+#[macro_export]
+macro_rules! for_each_ref {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(for_each_ref::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
+    ($path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(for_each_ref::GIT_COMMAND, $path);
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     }
+}
+
+#[macro_export]
+macro_rules! cat_file {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(cat_file::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
+    ($path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(cat_file::GIT_COMMAND, $path);
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     }
+}
+
+#[macro_export]
+macro_rules! ls_tree {
+    (global: [$($globals:expr), *],
+     $path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(ls_tree::GIT_COMMAND, $path);
+            $(
+                command.global_option($globals);
+            )*
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     };
+    ($path:expr,
+     $($options:expr), *) => {
+        {
+            let mut command = git(ls_tree::GIT_COMMAND, $path);
+            $(
+                command.option($options);
+            )*
+            command
+        }
+     }
+}
@@ -0,0 +1,129 @@
+use crate::optionarg;
+use crate::wrap_command::FnOptionArg;
+
+pub const DIRECTORY: &str = "-C";
+pub const CONFIG: &str = "-c";
+pub const CONFIG_ENV: &str = "--config-env";
+pub const EXEC_PATH: &str = "--exec-path";
+pub const PAGINATE: &str = "--paginate";
+pub const NO_PAGER: &str = "--no-pager";
+pub const GIT_DIR: &str = "--git-dir";
+pub const WORK_TREE: &str = "--work-tree";
+pub const NAMESPACE: &str = "--namespace";
+pub const BARE: &str = "--bare";
+pub const NO_REPLACE_OBJECTS: &str = "--no-replace-objects";
+pub const LITERAL_PATHSPECS: &str = "--literal-pathspecs";
+pub const GLOB_PATHSPECS: &str = "--glob-pathspecs";
+pub const NOGLOB_PATHSPECS: &str = "--noglob-pathspecs";
+pub const ICASE_PATHSPECS: &str = "--icase-pathspecs";
+pub const NO_OPTIONAL_LOCKS: &str = "--no-optional-locks";
+
+/// Run as if git was started in <path> instead of the current working directory.
+/// When multiple -C options are given, each subsequent non-absolute -C <path> is interpreted relative to the preceding -C <path>.
+/// -C <path>
+pub fn directory(path: &str) -> FnOptionArg {
+    optionarg::with_parameter(DIRECTORY, path)
+}
+
+/// Pass a configuration parameter to the command.
+/// The value given will override values from configuration files.
+/// -c <name>=<value>
+pub fn config(name: &str, value: &str) -> FnOptionArg {
+    optionarg::with_parameter(CONFIG, format!("{name}={value}").as_str())
+}
+
+/// Like -c <name>=<value>, give configuration variable <name> a value, where <envvar> is the name of an environment variable from which to retrieve the value.
+/// Unlike -c there is no shortcut for directly setting the value to an empty string, instead the environment variable itself must be set to the empty string.
+/// --config-env=<name>=<envvar>
+pub fn config_env(name: &str, envvar: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(CONFIG_ENV, format!("{name}={envvar}").as_str())
+}
+
+/// Path to wherever your core Git programs are installed.
+/// If no path is given, git will print the current setting and then exit.
+/// --exec-path[=<path>]
+pub fn exec_path(path: &str) -> FnOptionArg {
+    optionarg::equal_optional(EXEC_PATH, path)
+}
+
+/// Pipe all output into less (or if set, $PAGER) if standard output is a terminal.
+/// -p, --paginate
+pub fn paginate() -> FnOptionArg {
+    optionarg::simple(PAGINATE)
+}
+
+/// Do not pipe Git output into a pager.
+/// -P, --no-pager
+pub fn no_pager() -> FnOptionArg {
+    optionarg::simple(NO_PAGER)
+}
+
+/// Set the path to the repository (".git" directory).
+/// This can also be controlled by setting the GIT_DIR environment variable.
+/// --git-dir=<path>
+pub fn git_dir(path: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(GIT_DIR, path)
+}
+
+/// Set the path to the working tree.
+/// It can be an absolute path or a path relative to the current working directory.
+/// This can also be controlled by setting the GIT_WORK_TREE environment variable.
+/// --work-tree=<path>
+pub fn work_tree(path: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(WORK_TREE, path)
+}
+
+/// Set the Git namespace.
+/// Equivalent to setting the GIT_NAMESPACE environment variable.
+/// --namespace=<path>
+pub fn namespace(path: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(NAMESPACE, path)
+}
+
+/// Treat the repository as a bare repository.
+/// If GIT_DIR environment is not set, it is set to the current working directory.
+/// --bare
+pub fn bare() -> FnOptionArg {
+    optionarg::simple(BARE)
+}
+
+/// Do not use replacement refs to replace Git objects.
+/// --no-replace-objects
+pub fn no_replace_objects() -> FnOptionArg {
+    optionarg::simple(NO_REPLACE_OBJECTS)
+}
+
+/// Treat pathspecs literally (i.e. no globbing, no pathspec magic).
+/// This is equivalent to setting the GIT_LITERAL_PATHSPECS environment variable to 1.
+/// --literal-pathspecs
+pub fn literal_pathspecs() -> FnOptionArg {
+    optionarg::simple(LITERAL_PATHSPECS)
+}
+
+/// Add "glob" magic to all pathspec.
+/// This is equivalent to setting the GIT_GLOB_PATHSPECS environment variable to 1.
+/// --glob-pathspecs
+pub fn glob_pathspecs() -> FnOptionArg {
+    optionarg::simple(GLOB_PATHSPECS)
+}
+
+/// Add "literal" magic to all pathspec.
+/// This is equivalent to setting the GIT_NOGLOB_PATHSPECS environment variable to 1.
+/// --noglob-pathspecs
+pub fn noglob_pathspecs() -> FnOptionArg {
+    optionarg::simple(NOGLOB_PATHSPECS)
+}
+
+/// Add "icase" magic to all pathspec.
+/// This is equivalent to setting the GIT_ICASE_PATHSPECS environment variable to 1.
+/// --icase-pathspecs
+pub fn icase_pathspecs() -> FnOptionArg {
+    optionarg::simple(ICASE_PATHSPECS)
+}
+
+/// Do not perform optional operations that require locks.
+/// This is equivalent to setting the GIT_OPTIONAL_LOCKS to 0.
+/// --no-optional-locks
+pub fn no_optional_locks() -> FnOptionArg {
+    optionarg::simple(NO_OPTIONAL_LOCKS)
+}
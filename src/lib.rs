@@ -1,13 +1,32 @@
 pub mod wrap_command;
+pub mod wrap_stream;
+pub mod executor;
 mod error;
 pub use error::*;
 
+mod version;
+pub use version::*;
+
+mod control;
+pub use control::CancelHandle;
+
+#[cfg(feature = "async")]
+mod wrap_async;
+
 mod git_command;
 pub use git_command::*;
 
+mod repository;
+pub use repository::*;
+
+mod sequencer;
+pub use sequencer::*;
+
 #[cfg(test)]
 mod tests;
 mod optionarg;
+mod parser;
+pub mod global;
 
 pub mod tag;
 pub mod add;
@@ -28,4 +47,17 @@ pub mod rebase;
 pub mod reset;
 pub mod rev_parse;
 pub mod status;
+pub mod log;
+pub mod diff;
+pub mod stash;
+pub mod worktree;
+pub mod submodule;
+pub mod remote;
+pub mod cherry_pick;
+pub mod revert;
+pub mod bisect;
+pub mod blame;
+pub mod for_each_ref;
+pub mod cat_file;
+pub mod ls_tree;
 
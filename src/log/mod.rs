@@ -0,0 +1,16 @@
+use crate::wrap_command::WrapCommand;
+use crate::git;
+
+mod options;
+pub use options::*;
+mod parse;
+pub use parse::*;
+
+pub const GIT_COMMAND: &str = "log";
+
+/// Show commit logs.
+/// List commits that are reachable by following the parent links from the given commit(s), but exclude commits that are reachable from the one(s) given with a ^ in front of them
+/// [Git doc](https://git-scm.com/docs/git-log)
+pub fn log(current_dir: Option<&str>) -> WrapCommand {
+    git(GIT_COMMAND, current_dir)
+}
@@ -0,0 +1,359 @@
+// Warning!! Code generated automatically: this file must not be edited by hand
+use crate::optionarg;
+use crate::wrap_command::FnOptionArg;
+
+pub const FOLLOW: &str = "--follow";
+pub const DECORATE: &str = "--decorate";
+pub const NO_DECORATE: &str = "--no-decorate";
+pub const SOURCE: &str = "--source";
+pub const MAX_COUNT: &str = "--max-count";
+pub const SKIP: &str = "--skip";
+pub const SINCE: &str = "--since";
+pub const AFTER: &str = "--after";
+pub const UNTIL: &str = "--until";
+pub const BEFORE: &str = "--before";
+pub const AUTHOR: &str = "--author";
+pub const COMMITTER: &str = "--committer";
+pub const GREP: &str = "--grep";
+pub const ALL_MATCH: &str = "--all-match";
+pub const INVERT_GREP: &str = "--invert-grep";
+pub const REGEXP_IGNORE_CASE: &str = "--regexp-ignore-case";
+pub const FIXED_STRINGS: &str = "--fixed-strings";
+pub const MERGES: &str = "--merges";
+pub const NO_MERGES: &str = "--no-merges";
+pub const FIRST_PARENT: &str = "--first-parent";
+pub const ALL: &str = "--all";
+pub const BRANCHES: &str = "--branches";
+pub const TAGS: &str = "--tags";
+pub const REMOTES: &str = "--remotes";
+pub const ANCESTRY_PATH: &str = "--ancestry-path";
+pub const FULL_HISTORY: &str = "--full-history";
+pub const DATE_ORDER: &str = "--date-order";
+pub const AUTHOR_DATE_ORDER: &str = "--author-date-order";
+pub const TOPO_ORDER: &str = "--topo-order";
+pub const REVERSE: &str = "--reverse";
+pub const PRETTY: &str = "--pretty";
+pub const FORMAT: &str = "--format";
+pub const ABBREV_COMMIT: &str = "--abbrev-commit";
+pub const NO_ABBREV_COMMIT: &str = "--no-abbrev-commit";
+pub const ONELINE: &str = "--oneline";
+pub const ENCODING: &str = "--encoding";
+pub const DATE: &str = "--date";
+pub const PARENTS: &str = "--parents";
+pub const GRAPH: &str = "--graph";
+pub const NULL: &str = "-z";
+pub const PATCH: &str = "--patch";
+pub const NO_PATCH: &str = "--no-patch";
+pub const STAT: &str = "--stat";
+pub const NAME_ONLY: &str = "--name-only";
+pub const NAME_STATUS: &str = "--name-status";
+pub const NO_COLOR: &str = "--no-color";
+pub const HYPHEN_HYPHEN: &str = "--";
+
+/// Continue listing the history of a file beyond renames (works only for a single file).
+/// --follow
+pub fn follow() -> FnOptionArg {
+    optionarg::simple(FOLLOW)
+}
+
+/// Print out the ref names of any commits that are shown.
+/// --decorate[=short|full|auto|no]
+pub fn decorate(value: &str) -> FnOptionArg {
+    optionarg::equal_optional(DECORATE, value)
+}
+
+/// Do not print out the ref names of any commits that are shown.
+/// --no-decorate
+pub fn no_decorate() -> FnOptionArg {
+    optionarg::simple(NO_DECORATE)
+}
+
+/// Print out the ref name given on the command line by which each commit was reached.
+/// --source
+pub fn source() -> FnOptionArg {
+    optionarg::simple(SOURCE)
+}
+
+/// Limit the number of commits to output.
+/// -n <number>, --max-count=<number>
+pub fn max_count(number_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(MAX_COUNT, number_arg)
+}
+
+/// Skip number commits before starting to show the commit output.
+/// --skip=<number>
+pub fn skip(number_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(SKIP, number_arg)
+}
+
+/// Show commits more recent than a specific date.
+/// --since=<date>
+pub fn since(date_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(SINCE, date_arg)
+}
+
+/// Show commits more recent than a specific date.
+/// Same as --since.
+/// --after=<date>
+pub fn after(date_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(AFTER, date_arg)
+}
+
+/// Show commits older than a specific date.
+/// --until=<date>
+pub fn until(date_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(UNTIL, date_arg)
+}
+
+/// Show commits older than a specific date.
+/// Same as --until.
+/// --before=<date>
+pub fn before(date_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(BEFORE, date_arg)
+}
+
+/// Limit the commits output to ones with author header lines that match the specified pattern (regular expression).
+/// With more than one --author=<pattern>, commits whose author matches any of the given patterns are chosen.
+/// --author=<pattern>
+pub fn author(pattern_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(AUTHOR, pattern_arg)
+}
+
+/// Limit the commits output to ones with committer header lines that match the specified pattern (regular expression).
+/// With more than one --committer=<pattern>, commits whose committer matches any of the given patterns are chosen.
+/// --committer=<pattern>
+pub fn committer(pattern_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(COMMITTER, pattern_arg)
+}
+
+/// Limit the commits output to ones with a log message that matches the specified pattern (regular expression).
+/// With more than one --grep=<pattern>, commits whose message matches any of the given patterns are chosen (but see --all-match).
+/// --grep=<pattern>
+pub fn grep(pattern_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(GREP, pattern_arg)
+}
+
+/// Limit the commits output to ones that match all given --grep, instead of ones that match at least one.
+/// --all-match
+pub fn all_match() -> FnOptionArg {
+    optionarg::simple(ALL_MATCH)
+}
+
+/// Limit the commits output to ones with a log message that do not match the pattern specified with --grep=<pattern>.
+/// --invert-grep
+pub fn invert_grep() -> FnOptionArg {
+    optionarg::simple(INVERT_GREP)
+}
+
+/// Match the regular expression limiting patterns without regard to letter case.
+/// -i, --regexp-ignore-case
+pub fn regexp_ignore_case() -> FnOptionArg {
+    optionarg::simple(REGEXP_IGNORE_CASE)
+}
+
+/// Consider the limiting patterns to be fixed strings (don’t interpret pattern as a regular expression).
+/// -F, --fixed-strings
+pub fn fixed_strings() -> FnOptionArg {
+    optionarg::simple(FIXED_STRINGS)
+}
+
+/// Print only merge commits.
+/// This is exactly the same as --min-parents=2.
+/// --merges
+pub fn merges() -> FnOptionArg {
+    optionarg::simple(MERGES)
+}
+
+/// Do not print commits with more than one parent.
+/// This is exactly the same as --max-parents=1.
+/// --no-merges
+pub fn no_merges() -> FnOptionArg {
+    optionarg::simple(NO_MERGES)
+}
+
+/// When finding commits to include, follow only the first parent commit upon seeing a merge commit.
+/// --first-parent
+pub fn first_parent() -> FnOptionArg {
+    optionarg::simple(FIRST_PARENT)
+}
+
+/// Pretend as if all the refs in refs/, along with HEAD, are listed on the command line as <commit>.
+/// --all
+pub fn all() -> FnOptionArg {
+    optionarg::simple(ALL)
+}
+
+/// Pretend as if all the refs in refs/heads are listed on the command line as <commit>.
+/// If <pattern> is given, limit branches to ones matching given shell glob.
+/// --branches[=<pattern>]
+pub fn branches(pattern_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(BRANCHES, pattern_arg)
+}
+
+/// Pretend as if all the refs in refs/tags are listed on the command line as <commit>.
+/// If <pattern> is given, limit tags to ones matching given shell glob.
+/// --tags[=<pattern>]
+pub fn tags(pattern_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(TAGS, pattern_arg)
+}
+
+/// Pretend as if all the refs in refs/remotes are listed on the command line as <commit>.
+/// If <pattern> is given, limit remote-tracking branches to ones matching given shell glob.
+/// --remotes[=<pattern>]
+pub fn remotes(pattern_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(REMOTES, pattern_arg)
+}
+
+/// When given a range of commits to display (e.g. commit1..commit2 or commit2 ^commit1), only display commits that exist directly on the ancestry chain between the commit1 and commit2.
+/// --ancestry-path
+pub fn ancestry_path() -> FnOptionArg {
+    optionarg::simple(ANCESTRY_PATH)
+}
+
+/// Do not prune some history when simplifying it for the given paths.
+/// --full-history
+pub fn full_history() -> FnOptionArg {
+    optionarg::simple(FULL_HISTORY)
+}
+
+/// Show no parents before all of its children are shown, but otherwise show commits in the commit timestamp order.
+/// --date-order
+pub fn date_order() -> FnOptionArg {
+    optionarg::simple(DATE_ORDER)
+}
+
+/// Show no parents before all of its children are shown, but otherwise show commits in the author timestamp order.
+/// --author-date-order
+pub fn author_date_order() -> FnOptionArg {
+    optionarg::simple(AUTHOR_DATE_ORDER)
+}
+
+/// Show no parents before all of its children are shown, and avoid showing commits on multiple lines of history intermixed.
+/// --topo-order
+pub fn topo_order() -> FnOptionArg {
+    optionarg::simple(TOPO_ORDER)
+}
+
+/// Output the commits chosen to be shown in reverse order.
+/// --reverse
+pub fn reverse() -> FnOptionArg {
+    optionarg::simple(REVERSE)
+}
+
+/// Pretty-print the contents of the commit logs in a given format, where <format> can be one of oneline, short, medium, full, fuller, reference, email, raw, format:<string> and tformat:<string>.
+/// --pretty[=<format>]
+pub fn pretty(format_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(PRETTY, format_arg)
+}
+
+/// Pretty-print the contents of the commit logs in a given format, see --pretty.
+/// A format holding placeholders such as %H is handled as tformat:<format>.
+/// --format=<format>
+pub fn format(format_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(FORMAT, format_arg)
+}
+
+/// Instead of showing the full 40-byte hexadecimal commit object name, show a prefix that names the object uniquely.
+/// --abbrev-commit
+pub fn abbrev_commit() -> FnOptionArg {
+    optionarg::simple(ABBREV_COMMIT)
+}
+
+/// Show the full 40-byte hexadecimal commit object name.
+/// --no-abbrev-commit
+pub fn no_abbrev_commit() -> FnOptionArg {
+    optionarg::simple(NO_ABBREV_COMMIT)
+}
+
+/// This is a shorthand for "--pretty=oneline --abbrev-commit" used together.
+/// --oneline
+pub fn oneline() -> FnOptionArg {
+    optionarg::simple(ONELINE)
+}
+
+/// Re-code the commit log message in the given encoding.
+/// --encoding=<encoding>
+pub fn encoding(encoding_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(ENCODING, encoding_arg)
+}
+
+/// Only takes effect for dates shown in human-readable format, such as when using --pretty.
+/// <format> can be one of relative, local, iso, iso-strict, rfc, short, raw, human, unix, format:<strftime>, default.
+/// --date=<format>
+pub fn date(format_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(DATE, format_arg)
+}
+
+/// Print also the parents of the commit (in the form "commit parent…").
+/// --parents
+pub fn parents() -> FnOptionArg {
+    optionarg::simple(PARENTS)
+}
+
+/// Draw a text-based graphical representation of the commit history on the left hand side of the output.
+/// --graph
+pub fn graph() -> FnOptionArg {
+    optionarg::simple(GRAPH)
+}
+
+/// Separate the commits with NULs instead of newlines.
+/// -z
+pub fn null() -> FnOptionArg {
+    optionarg::simple(NULL)
+}
+
+/// Generate patch.
+/// -p, --patch
+pub fn patch() -> FnOptionArg {
+    optionarg::simple(PATCH)
+}
+
+/// Suppress all output from the diff machinery.
+/// -s, --no-patch
+pub fn no_patch() -> FnOptionArg {
+    optionarg::simple(NO_PATCH)
+}
+
+/// Generate a diffstat.
+/// --stat
+pub fn stat() -> FnOptionArg {
+    optionarg::simple(STAT)
+}
+
+/// Show only names of changed files.
+/// --name-only
+pub fn name_only() -> FnOptionArg {
+    optionarg::simple(NAME_ONLY)
+}
+
+/// Show only names and status of changed files.
+/// --name-status
+pub fn name_status() -> FnOptionArg {
+    optionarg::simple(NAME_STATUS)
+}
+
+/// Turn off colored output.
+/// --no-color
+pub fn no_color() -> FnOptionArg {
+    optionarg::simple(NO_COLOR)
+}
+
+/// Show only commits in the specified revision range.
+/// When no <revision-range> is specified, it defaults to HEAD (i.e. the whole history leading to the current commit).
+/// origin..HEAD specifies all the commits reachable from the current commit (i.e. HEAD), but not from origin.
+/// <revision_range>
+pub fn revision_range(revision_range: &str) -> FnOptionArg {
+    optionarg::value_parameter(revision_range)
+}
+
+/// Do not interpret any more arguments as options.
+/// --
+pub fn hyphen_hyphen() -> FnOptionArg {
+    optionarg::simple(HYPHEN_HYPHEN)
+}
+
+/// Show only commits that are enough to explain how the files that match the specified paths came to be.
+/// <pathspec>
+pub fn pathspec(pathspec: &str) -> FnOptionArg {
+    optionarg::value_parameter(pathspec)
+}
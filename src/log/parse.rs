@@ -0,0 +1,139 @@
+use crate::log::format;
+use crate::parser::parse_error;
+use crate::wrap_command::{FnOptionArg, WrapCommand};
+use crate::wrap_stream::{StreamLine, WrapChild};
+use crate::WrapError;
+
+const RECORD_SEPARATOR: char = '\x1e';
+const FIELD_SEPARATOR: char = '\0';
+const FIELDS: usize = 13;
+
+/// The format read by [parse_commits] and [commits]: every commit starts with a record separator,
+/// and its fields are NUL-separated
+pub const COMMIT_FORMAT: &str = "%x1e%H%x00%P%x00%an%x00%ae%x00%at%x00%aI%x00%cn%x00%ce%x00%ct%x00%cI%x00%s%x00%b%x00%(trailers:only,unfold)";
+
+/// A commit, as read from `git log --format=<COMMIT_FORMAT>`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Commit {
+    pub oid: String,
+    /// Parent commits, none for a root commit and several for a merge
+    pub parents: Vec<String>,
+    pub author: Signature,
+    pub committer: Signature,
+    /// First line of the commit message
+    pub subject: String,
+    /// Commit message after the subject and the blank line following it, trailers included
+    pub body: String,
+    pub trailers: Vec<Trailer>,
+}
+
+/// Identity and date of the author or the committer of a commit
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Signature {
+    pub name: String,
+    pub email: String,
+    /// Seconds since the UNIX epoch
+    pub time: i64,
+    /// Strict ISO 8601 date holding the original timezone, e.g. 2005-04-07T22:13:13+02:00
+    pub date: String,
+}
+
+/// A `key: value` trailer at the end of a commit message, e.g. `Signed-off-by: A U Thor <author@example.com>`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Trailer {
+    pub key: String,
+    pub value: String,
+}
+
+/// Sets the log output format read by [parse_commits] and [commits]
+pub fn commit_format() -> FnOptionArg {
+    format(COMMIT_FORMAT)
+}
+
+/// Parses the whole output of a `git log` run with the [commit_format] option
+pub fn parse_commits(output: &str) -> Result<Vec<Commit>, WrapError> {
+    output.split(RECORD_SEPARATOR)
+        .filter(|r| !r.trim().is_empty())
+        .map(parse_commit)
+        .collect()
+}
+
+/// Runs a `git log` command built with the [commit_format] option,
+/// returning an iterator parsing commits as soon as git outputs them.
+/// Large histories are never buffered as a whole.
+pub fn commits(cmd: &WrapCommand) -> Result<Commits, WrapError> {
+    Ok(Commits {
+        child: Some(cmd.spawn()?),
+        record: None,
+    })
+}
+
+/// Iterator over the commits of a running `git log`, see [commits].
+/// A git failure is reported as the last item.
+pub struct Commits {
+    child: Option<WrapChild>,
+    record: Option<String>,
+}
+
+impl Iterator for Commits {
+    type Item = Result<Commit, WrapError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(child) = self.child.as_mut() {
+            match child.next() {
+                Some(StreamLine::Stdout(line)) => match line.strip_prefix(RECORD_SEPARATOR) {
+                    Some(start) => {
+                        if let Some(record) = self.record.replace(String::from(start)) {
+                            return Some(parse_commit(record.as_str()));
+                        }
+                    }
+                    None => match self.record.as_mut() {
+                        Some(record) => {
+                            record.push('\n');
+                            record.push_str(line.as_str());
+                        }
+                        None if line.is_empty() => {}
+                        None => return Some(Err(parse_error("commit record", line.as_str()))),
+                    },
+                },
+                Some(_) => {}
+                None => {
+                    if let Some(record) = self.record.take() {
+                        return Some(parse_commit(record.as_str()));
+                    }
+                    return self.child.take().and_then(|c| c.wait().err()).map(Err);
+                }
+            }
+        }
+        None
+    }
+}
+
+fn parse_commit(record: &str) -> Result<Commit, WrapError> {
+    let f: Vec<&str> = record.splitn(FIELDS, FIELD_SEPARATOR).collect();
+    if f.len() != FIELDS {
+        return Err(parse_error("commit record", record));
+    }
+    Ok(Commit {
+        oid: String::from(f[0]),
+        parents: f[1].split_whitespace().map(String::from).collect(),
+        author: signature(&f[2..6])?,
+        committer: signature(&f[6..10])?,
+        subject: String::from(f[10]),
+        body: String::from(f[11].trim_end_matches('\n')),
+        trailers: f[12].lines()
+            .filter_map(|t| t.split_once(':'))
+            .map(|(key, value)| Trailer { key: String::from(key.trim()), value: String::from(value.trim()) })
+            .collect(),
+    })
+}
+
+// name, email, unix time, ISO 8601 date
+fn signature(f: &[&str]) -> Result<Signature, WrapError> {
+    Ok(Signature {
+        name: String::from(f[0]),
+        email: String::from(f[1]),
+        time: f[2].parse().map_err(|_| parse_error("commit time", f[2]))?,
+        date: String::from(f[3]),
+    })
+}
@@ -0,0 +1,17 @@
+use crate::wrap_command::WrapCommand;
+use crate::git;
+
+mod options;
+pub use options::*;
+
+mod parse;
+pub use parse::*;
+
+pub const GIT_COMMAND: &str = "ls-tree";
+
+/// List the contents of a tree object.
+/// Lists the contents of a given tree object, like what "/bin/ls -a" does in the current working directory.
+/// [Git doc](https://git-scm.com/docs/git-ls-tree)
+pub fn ls_tree(current_dir: Option<&str>) -> WrapCommand {
+    git(GIT_COMMAND, current_dir)
+}
@@ -0,0 +1,106 @@
+// Warning!! Code generated automatically: this file must not be edited by hand
+use crate::optionarg;
+use crate::wrap_command::FnOptionArg;
+
+pub const ONLY_TREES: &str = "-d";
+pub const RECURSIVE: &str = "-r";
+pub const SHOW_TREES: &str = "-t";
+pub const LONG: &str = "--long";
+pub const NULL: &str = "-z";
+pub const NAME_ONLY: &str = "--name-only";
+pub const NAME_STATUS: &str = "--name-status";
+pub const OBJECT_ONLY: &str = "--object-only";
+pub const ABBREV: &str = "--abbrev";
+pub const FULL_NAME: &str = "--full-name";
+pub const FULL_TREE: &str = "--full-tree";
+pub const FORMAT: &str = "--format";
+
+/// Show only the named tree entry itself, not its children.
+/// -d
+pub fn only_trees() -> FnOptionArg {
+    optionarg::simple(ONLY_TREES)
+}
+
+/// Recurse into sub-trees.
+/// -r
+pub fn recursive() -> FnOptionArg {
+    optionarg::simple(RECURSIVE)
+}
+
+/// Show tree entries even when going to recurse them.
+/// Has no effect if -r was not passed.
+/// -d implies -t.
+/// -t
+pub fn show_trees() -> FnOptionArg {
+    optionarg::simple(SHOW_TREES)
+}
+
+/// Show object size of blob (file) entries.
+/// -l, --long
+pub fn long() -> FnOptionArg {
+    optionarg::simple(LONG)
+}
+
+/// \0 line termination on output and do not quote filenames.
+/// -z
+pub fn null() -> FnOptionArg {
+    optionarg::simple(NULL)
+}
+
+/// List only filenames (instead of the "long" output), one per line.
+/// --name-only
+pub fn name_only() -> FnOptionArg {
+    optionarg::simple(NAME_ONLY)
+}
+
+/// List only filenames (instead of the "long" output), one per line.
+/// Synonym of --name-only.
+/// --name-status
+pub fn name_status() -> FnOptionArg {
+    optionarg::simple(NAME_STATUS)
+}
+
+/// List only names of the objects, one per line.
+/// Cannot be combined with --name-only or --name-status.
+/// --object-only
+pub fn object_only() -> FnOptionArg {
+    optionarg::simple(OBJECT_ONLY)
+}
+
+/// Instead of showing the full 40-byte hexadecimal object lines, show the shortest prefix that is at least <n> hexdigits long that uniquely refers the object.
+/// --abbrev=<n>
+pub fn abbrev(n_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(ABBREV, n_arg)
+}
+
+/// Instead of showing the path names relative to the current working directory, show the full path names.
+/// --full-name
+pub fn full_name() -> FnOptionArg {
+    optionarg::simple(FULL_NAME)
+}
+
+/// Do not limit the listing to the current working directory.
+/// Implies --full-name.
+/// --full-tree
+pub fn full_tree() -> FnOptionArg {
+    optionarg::simple(FULL_TREE)
+}
+
+/// A string that interpolates %(fieldname) from the result being shown.
+/// It also interpolates %% to %, and %xx where xx are hex digits interpolates to character with hex code xx.
+/// --format=<format>
+pub fn format(format_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(FORMAT, format_arg)
+}
+
+/// Id of a tree-ish.
+/// <tree_ish>
+pub fn tree_ish(tree_ish: &str) -> FnOptionArg {
+    optionarg::value_parameter(tree_ish)
+}
+
+/// When paths are given, show them (note that this isn’t really raw pathnames, but rather a list of patterns to match).
+/// <path>
+pub fn path(path: &str) -> FnOptionArg {
+    optionarg::value_parameter(path)
+}
@@ -0,0 +1,86 @@
+use crate::cat_file::ObjectKind;
+use crate::ls_tree::{full_tree, long, ls_tree, recursive, tree_ish};
+use crate::parser::{is_nul_terminated, parse_error, parse_mode, records, unquote_path};
+use crate::wrap_command::WrapCommand;
+use crate::wrap_stream::{StreamLine, WrapChild};
+use crate::WrapError;
+
+/// An entry of a tree, as listed by `git ls-tree`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TreeEntry {
+    /// File mode, e.g. 0o100644 for a regular file or 0o040000 for a tree
+    pub mode: u32,
+    /// Blob for files and symlinks, tree for directories, commit for submodules
+    pub kind: ObjectKind,
+    pub oid: String,
+    /// Size of blob entries, only listed with `--long`
+    pub size: Option<u64>,
+    pub path: String,
+}
+
+/// Parses the output of `git ls-tree` in its default or `--long` format, with or without `-z`, e.g.
+/// `100644 blob 8baef1b4abc478178b004d62031cf7fe6db6f903      12\tREADME.md`
+pub fn parse_ls_tree(output: &str) -> Result<Vec<TreeEntry>, WrapError> {
+    let nul = is_nul_terminated(output);
+    records(output).into_iter().map(|r| parse_entry(r, nul)).collect()
+}
+
+/// Runs a `git ls-tree` command printing the default or `--long` format without `-z`,
+/// returning an iterator parsing entries as soon as git outputs them
+pub fn tree_entries(cmd: &WrapCommand) -> Result<TreeEntries, WrapError> {
+    Ok(TreeEntries { child: Some(cmd.spawn()?) })
+}
+
+/// Walks the whole tree of [tree_ish] (a commit, a tag or a tree) recursively, listing every file with its size.
+/// The working tree and the index are never read.
+pub fn walk_tree(current_dir: Option<&str>, tree_ish_arg: &str) -> Result<TreeEntries, WrapError> {
+    let mut cmd = ls_tree(current_dir);
+    cmd.option(recursive());
+    cmd.option(long());
+    cmd.option(full_tree());
+    cmd.option(tree_ish(tree_ish_arg));
+    tree_entries(&cmd)
+}
+
+/// Iterator over the entries of a running `git ls-tree`, see [tree_entries].
+/// A git failure is reported as the last item.
+pub struct TreeEntries {
+    child: Option<WrapChild>,
+}
+
+impl Iterator for TreeEntries {
+    type Item = Result<TreeEntry, WrapError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(child) = self.child.as_mut() {
+            match child.next() {
+                Some(StreamLine::Stdout(line)) if line.is_empty() => {}
+                Some(StreamLine::Stdout(line)) => return Some(parse_entry(line.as_str(), false)),
+                Some(_) => {}
+                None => return self.child.take().and_then(|c| c.wait().err()).map(Err),
+            }
+        }
+        None
+    }
+}
+
+// <mode> SP <type> SP <object> [SP+ <size>] TAB <path>
+fn parse_entry(record: &str, nul: bool) -> Result<TreeEntry, WrapError> {
+    let (meta, path) = record.split_once('\t').ok_or_else(|| parse_error("tree entry", record))?;
+    let mut f = meta.split_whitespace();
+    let (Some(mode), Some(kind), Some(oid)) = (f.next(), f.next(), f.next()) else {
+        return Err(parse_error("tree entry", record));
+    };
+    let size = match f.next() {
+        None | Some("-") => None,
+        Some(size) => Some(size.parse().map_err(|_| parse_error("object size", size))?),
+    };
+
+    Ok(TreeEntry {
+        mode: parse_mode(mode)?,
+        kind: ObjectKind::from_name(kind).ok_or_else(|| parse_error("object type", kind))?,
+        oid: String::from(oid),
+        size,
+        path: if nul { String::from(path) } else { unquote_path(path) },
+    })
+}
@@ -0,0 +1,64 @@
+use crate::WrapError;
+
+/// Splits git output into records: NUL-terminated if the output holds any NUL byte (`-z` option),
+/// newline-terminated otherwise
+pub(crate) fn records(output: &str) -> Vec<&str> {
+    let separator = if output.contains('\0') { '\0' } else { '\n' };
+    output.split(separator).filter(|r| !r.is_empty()).collect()
+}
+
+/// Returns true if the output is NUL-terminated (`-z` option)
+pub(crate) fn is_nul_terminated(output: &str) -> bool {
+    output.contains('\0')
+}
+
+/// Removes the C-style quoting git applies to paths holding special characters (see core.quotePath),
+/// e.g. `"dir/tab\there \303\251"`. Unquoted paths are returned as is.
+pub(crate) fn unquote_path(path: &str) -> String {
+    let Some(inner) = path.strip_prefix('"').and_then(|p| p.strip_suffix('"')) else {
+        return String::from(path);
+    };
+    let mut bytes: Vec<u8> = Vec::with_capacity(inner.len());
+    let mut chars = inner.bytes().peekable();
+    while let Some(b) = chars.next() {
+        if b != b'\\' {
+            bytes.push(b);
+            continue;
+        }
+        match chars.next() {
+            Some(b'n') => bytes.push(b'\n'),
+            Some(b't') => bytes.push(b'\t'),
+            Some(b'r') => bytes.push(b'\r'),
+            Some(b'a') => bytes.push(0x07),
+            Some(b'b') => bytes.push(0x08),
+            Some(b'f') => bytes.push(0x0c),
+            Some(b'v') => bytes.push(0x0b),
+            Some(d @ b'0'..=b'7') => {
+                let mut value = (d - b'0') as u32;
+                for _ in 0..2 {
+                    match chars.peek() {
+                        Some(n @ b'0'..=b'7') => {
+                            value = value * 8 + (n - b'0') as u32;
+                            chars.next();
+                        }
+                        _ => break,
+                    }
+                }
+                bytes.push(value as u8);
+            }
+            Some(other) => bytes.push(other),
+            None => bytes.push(b'\\'),
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// Parses an octal file mode, e.g. 100644
+pub(crate) fn parse_mode(mode: &str) -> Result<u32, WrapError> {
+    u32::from_str_radix(mode, 8).map_err(|_| parse_error("file mode", mode))
+}
+
+/// The error reported when git output does not have the expected shape
+pub(crate) fn parse_error(what: &str, input: &str) -> WrapError {
+    WrapError::ParseOutput(format!("invalid {what}: {input:?}"))
+}
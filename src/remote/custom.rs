@@ -0,0 +1,75 @@
+use crate::optionarg;
+use crate::wrap_command::FnOptionArg;
+
+pub const ADD: &str = "add";
+pub const RENAME: &str = "rename";
+pub const REMOVE: &str = "remove";
+pub const SET_HEAD: &str = "set-head";
+pub const SET_BRANCHES: &str = "set-branches";
+pub const GET_URL: &str = "get-url";
+pub const SET_URL: &str = "set-url";
+pub const SHOW: &str = "show";
+pub const PRUNE: &str = "prune";
+pub const UPDATE: &str = "update";
+
+/// Add a remote named <name> for the repository at <url>.
+/// add
+pub fn add() -> FnOptionArg {
+    optionarg::simple(ADD)
+}
+
+/// Rename the remote named <old> to <new>.
+/// All remote-tracking branches and configuration settings for the remote are updated.
+/// rename
+pub fn rename() -> FnOptionArg {
+    optionarg::simple(RENAME)
+}
+
+/// Remove the remote named <name>.
+/// All remote-tracking branches and configuration settings for the remote are removed.
+/// remove
+pub fn remove() -> FnOptionArg {
+    optionarg::simple(REMOVE)
+}
+
+/// Sets or deletes the default branch (i.e. the target of the symbolic-ref refs/remotes/<name>/HEAD) for the named remote.
+/// set-head
+pub fn set_head() -> FnOptionArg {
+    optionarg::simple(SET_HEAD)
+}
+
+/// Changes the list of branches tracked by the named remote.
+/// set-branches
+pub fn set_branches() -> FnOptionArg {
+    optionarg::simple(SET_BRANCHES)
+}
+
+/// Retrieves the URLs for a remote.
+/// get-url
+pub fn get_url() -> FnOptionArg {
+    optionarg::simple(GET_URL)
+}
+
+/// Changes URLs for the remote.
+/// set-url
+pub fn set_url() -> FnOptionArg {
+    optionarg::simple(SET_URL)
+}
+
+/// Gives some information about the remote <name>.
+/// show
+pub fn show() -> FnOptionArg {
+    optionarg::simple(SHOW)
+}
+
+/// Deletes stale references associated with <name>.
+/// prune
+pub fn prune() -> FnOptionArg {
+    optionarg::simple(PRUNE)
+}
+
+/// Fetch updates for remotes or remote groups in the repository as defined by remotes.<group>.
+/// update
+pub fn update() -> FnOptionArg {
+    optionarg::simple(UPDATE)
+}
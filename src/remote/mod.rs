@@ -0,0 +1,20 @@
+use crate::wrap_command::WrapCommand;
+use crate::git;
+
+mod options;
+pub use options::*;
+
+mod custom;
+pub use custom::*;
+
+mod parse;
+pub use parse::*;
+
+pub const GIT_COMMAND: &str = "remote";
+
+/// Manage the set of repositories ("remotes") whose branches you track.
+/// The subcommand is given as the first option, e.g. remote::add()
+/// [Git doc](https://git-scm.com/docs/git-remote)
+pub fn remote(current_dir: Option<&str>) -> WrapCommand {
+    git(GIT_COMMAND, current_dir)
+}
@@ -0,0 +1,131 @@
+// Warning!! Code generated automatically: this file must not be edited by hand
+use crate::optionarg;
+use crate::wrap_command::FnOptionArg;
+
+pub const VERBOSE: &str = "--verbose";
+pub const TRACK: &str = "-t";
+pub const MASTER: &str = "-m";
+pub const MIRROR: &str = "--mirror";
+pub const FETCH: &str = "-f";
+pub const TAGS: &str = "--tags";
+pub const NO_TAGS: &str = "--no-tags";
+pub const PUSH: &str = "--push";
+pub const ADD_URL: &str = "--add";
+pub const DELETE: &str = "--delete";
+pub const ALL: &str = "--all";
+pub const AUTO: &str = "--auto";
+pub const NO_QUERY: &str = "-n";
+pub const DRY_RUN: &str = "--dry-run";
+
+/// Be a little more verbose and show remote url after name.
+/// For promisor remotes, also show which filters (blob:none etc.) are configured.
+/// -v, --verbose
+pub fn verbose() -> FnOptionArg {
+    optionarg::simple(VERBOSE)
+}
+
+/// With add, instead of the default glob refspec for the remote to track all branches under the refs/remotes/<name>/ namespace, a refspec to track only <branch> is created.
+/// -t <branch>
+pub fn track(branch_arg: &str) -> FnOptionArg {
+    optionarg::with_parameter(TRACK, branch_arg)
+}
+
+/// With add, the symbolic-ref refs/remotes/<name>/HEAD is set up to point at remote’s <master> branch.
+/// -m <master>
+pub fn master(master_arg: &str) -> FnOptionArg {
+    optionarg::with_parameter(MASTER, master_arg)
+}
+
+/// With add, when a fetch mirror is created with --mirror=fetch, the refs will not be stored in the refs/remotes/ namespace, but rather everything in refs/ on the remote will be directly mirrored into refs/ in the local repository.
+/// When a push mirror is created with --mirror=push, then git push will always behave as if --mirror was passed.
+/// --mirror[=fetch|push]
+pub fn mirror(value: &str) -> FnOptionArg {
+    optionarg::equal_optional(MIRROR, value)
+}
+
+/// With add, git fetch <name> is run immediately after the remote information is set up.
+/// -f
+pub fn fetch() -> FnOptionArg {
+    optionarg::simple(FETCH)
+}
+
+/// With add, git fetch <name> imports every tag from the remote repository.
+/// --tags
+pub fn tags() -> FnOptionArg {
+    optionarg::simple(TAGS)
+}
+
+/// With add, git fetch <name> does not import tags from the remote repository.
+/// --no-tags
+pub fn no_tags() -> FnOptionArg {
+    optionarg::simple(NO_TAGS)
+}
+
+/// With set-url, push URLs are manipulated instead of fetch URLs.
+/// With get-url, push URLs are queried rather than fetch URLs.
+/// --push
+pub fn push() -> FnOptionArg {
+    optionarg::simple(PUSH)
+}
+
+/// With set-url, instead of changing existing URLs, new URL is added.
+/// --add
+pub fn add_url() -> FnOptionArg {
+    optionarg::simple(ADD_URL)
+}
+
+/// With set-url, instead of changing existing URLs, all URLs matching regex <url> are deleted.
+/// With set-head, the symbolic ref refs/remotes/<name>/HEAD is deleted.
+/// --delete
+pub fn delete() -> FnOptionArg {
+    optionarg::simple(DELETE)
+}
+
+/// With get-url, all URLs for the remote will be listed.
+/// --all
+pub fn all() -> FnOptionArg {
+    optionarg::simple(ALL)
+}
+
+/// With set-head, the remote is queried to determine its HEAD, then the symbolic-ref refs/remotes/<name>/HEAD is set to the same branch.
+/// -a, --auto
+pub fn auto() -> FnOptionArg {
+    optionarg::simple(AUTO)
+}
+
+/// With show and prune, the remote heads are not queried first with git ls-remote <name>; cached information is used instead.
+/// -n
+pub fn no_query() -> FnOptionArg {
+    optionarg::simple(NO_QUERY)
+}
+
+/// With prune, report what branches would be pruned, but do not actually prune them.
+/// --dry-run
+pub fn dry_run() -> FnOptionArg {
+    optionarg::simple(DRY_RUN)
+}
+
+/// Name of the remote.
+/// Give it twice to rename, old name first.
+/// <name>
+pub fn name(name: &str) -> FnOptionArg {
+    optionarg::value_parameter(name)
+}
+
+/// URL of the remote (add, set-url, set-url --add), or regex matching the URLs to delete (set-url --delete).
+/// <url>
+pub fn url(url: &str) -> FnOptionArg {
+    optionarg::value_parameter(url)
+}
+
+/// With set-url, regex matching the URL to change.
+/// <oldurl>
+pub fn old_url(oldurl: &str) -> FnOptionArg {
+    optionarg::value_parameter(oldurl)
+}
+
+/// With set-head, the branch refs/remotes/<name>/HEAD points at.
+/// <branch>
+pub fn branch(branch: &str) -> FnOptionArg {
+    optionarg::value_parameter(branch)
+}
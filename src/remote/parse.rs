@@ -0,0 +1,41 @@
+use crate::parser::{parse_error, records};
+use crate::WrapError;
+
+/// A remote, as listed by `git remote -v`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Remote {
+    pub name: String,
+    pub fetch_url: Option<String>,
+    /// URLs pushed to: the fetch URL, unless push URLs are configured
+    pub push_urls: Vec<String>,
+}
+
+/// Parses the output of `git remote -v`, e.g. `origin\thttps://example.com/repo.git (fetch)`.
+/// The output of `git remote` without `-v` gives remotes without URLs.
+pub fn parse_remotes(output: &str) -> Result<Vec<Remote>, WrapError> {
+    let mut remotes: Vec<Remote> = Vec::new();
+    for line in records(output) {
+        let (name, url) = line.split_once('\t').unwrap_or((line, ""));
+        if remotes.last().is_none_or(|r| r.name != name) {
+            remotes.push(Remote { name: String::from(name), ..Default::default() });
+        }
+        if url.is_empty() {
+            continue;
+        }
+
+        // promisor remotes are followed by their filter, e.g. `(fetch) [blob:none]`
+        let url = match url.rsplit_once(" [") {
+            Some((url, filter)) if filter.ends_with(']') => url,
+            _ => url,
+        };
+        let remote = remotes.last_mut().unwrap();
+        if let Some(fetch) = url.strip_suffix(" (fetch)") {
+            remote.fetch_url = Some(String::from(fetch));
+        } else if let Some(push) = url.strip_suffix(" (push)") {
+            remote.push_urls.push(String::from(push));
+        } else {
+            return Err(parse_error("remote", line));
+        }
+    }
+    Ok(remotes)
+}
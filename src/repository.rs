@@ -0,0 +1,239 @@
+use std::fs;
+use std::io;
+use crate::wrap_command::WrapCommand;
+use crate::{add, bisect, blame, branch, cat_file, checkout, cherry_pick, clean, commit, config, diff, fetch, for_each_ref, log, ls_files, ls_tree, merge, notes, pull, push, rebase, remote, reset, rev_parse, revert, stash, status, submodule, tag, worktree};
+use crate::{git, optionarg, sequencer_state, SequencerState, WrapError};
+
+const GIT_CEILING_DIRECTORIES: &str = "GIT_CEILING_DIRECTORIES";
+
+/// A git repository, opened or discovered from a path.
+/// Caches the git dir, the work tree and whether the repository is bare,
+/// and builds commands running inside the repository.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Repository {
+    git_dir: String,
+    work_tree: Option<String>,
+    bare: bool,
+}
+
+impl Repository {
+    /// Opens the repository whose work tree (or git dir, for a bare repository) is `path`.
+    /// Unlike [Repository::discover], parent directories of `path` are not searched.
+    pub fn open(path: &str) -> Result<Self, WrapError> {
+        let ceiling = fs::canonicalize(path).ok()
+            .and_then(|p| p.parent().map(|parent| parent.to_string_lossy().into_owned()));
+        Self::probe(path, ceiling)
+    }
+
+    /// Discovers the repository containing `path`, searching parent directories like git does
+    pub fn discover(path: &str) -> Result<Self, WrapError> {
+        Self::probe(path, None)
+    }
+
+    fn probe(path: &str, ceiling: Option<String>) -> Result<Self, WrapError> {
+        let run = |option| {
+            let mut cmd = rev_parse::rev_parse(Some(path));
+            if let Some(c) = ceiling.as_deref() {
+                cmd.env(GIT_CEILING_DIRECTORIES, c);
+            }
+            cmd.option(option);
+            cmd.execute_output().map(|o| String::from(o.stdout.trim_end_matches('\n')))
+        };
+
+        let git_dir = run(rev_parse::absolute_git_dir())?;
+        let bare = run(rev_parse::is_bare_repository())? == "true";
+        let work_tree = if bare { None } else { Some(run(rev_parse::show_toplevel())?) };
+
+        Ok(Self { git_dir, work_tree, bare })
+    }
+
+    /// Absolute path of the git dir, e.g. `/path/to/repo/.git`
+    pub fn git_dir(&self) -> &str {
+        self.git_dir.as_str()
+    }
+
+    /// Absolute path of the top-level directory of the work tree, None for a bare repository
+    pub fn work_tree(&self) -> Option<&str> {
+        self.work_tree.as_deref()
+    }
+
+    pub fn is_bare(&self) -> bool {
+        self.bare
+    }
+
+    /// The directory commands built by the repository run in: the work tree, or the git dir if bare
+    pub fn path(&self) -> &str {
+        self.work_tree.as_deref().unwrap_or(self.git_dir.as_str())
+    }
+
+    /// A command for any git subcommand, running inside the repository
+    pub fn git(&self, cmd: &str) -> WrapCommand {
+        git(cmd, Some(self.path()))
+    }
+
+    pub fn add(&self) -> WrapCommand {
+        add::add(Some(self.path()))
+    }
+
+    pub fn bisect(&self) -> WrapCommand {
+        bisect::bisect(Some(self.path()))
+    }
+
+    pub fn blame(&self) -> WrapCommand {
+        blame::blame(Some(self.path()))
+    }
+
+    pub fn branch(&self) -> WrapCommand {
+        branch::branch(Some(self.path()))
+    }
+
+    pub fn cat_file(&self) -> WrapCommand {
+        cat_file::cat_file(Some(self.path()))
+    }
+
+    pub fn checkout(&self) -> WrapCommand {
+        checkout::checkout(Some(self.path()))
+    }
+
+    pub fn cherry_pick(&self) -> WrapCommand {
+        cherry_pick::cherry_pick(Some(self.path()))
+    }
+
+    pub fn clean(&self) -> WrapCommand {
+        clean::clean(Some(self.path()))
+    }
+
+    pub fn commit(&self) -> WrapCommand {
+        commit::commit(Some(self.path()))
+    }
+
+    pub fn config(&self) -> WrapCommand {
+        config::config(Some(self.path()))
+    }
+
+    pub fn diff(&self) -> WrapCommand {
+        diff::diff(Some(self.path()))
+    }
+
+    pub fn fetch(&self) -> WrapCommand {
+        fetch::fetch(Some(self.path()))
+    }
+
+    pub fn for_each_ref(&self) -> WrapCommand {
+        for_each_ref::for_each_ref(Some(self.path()))
+    }
+
+    pub fn log(&self) -> WrapCommand {
+        log::log(Some(self.path()))
+    }
+
+    pub fn ls_files(&self) -> WrapCommand {
+        ls_files::ls_files(Some(self.path()))
+    }
+
+    pub fn ls_tree(&self) -> WrapCommand {
+        ls_tree::ls_tree(Some(self.path()))
+    }
+
+    pub fn merge(&self) -> WrapCommand {
+        merge::merge(Some(self.path()))
+    }
+
+    pub fn notes(&self) -> WrapCommand {
+        notes::notes(Some(self.path()))
+    }
+
+    pub fn pull(&self) -> WrapCommand {
+        pull::pull(Some(self.path()))
+    }
+
+    pub fn push(&self) -> WrapCommand {
+        push::push(Some(self.path()))
+    }
+
+    pub fn rebase(&self) -> WrapCommand {
+        rebase::rebase(Some(self.path()))
+    }
+
+    pub fn remote(&self) -> WrapCommand {
+        remote::remote(Some(self.path()))
+    }
+
+    pub fn reset(&self) -> WrapCommand {
+        reset::reset(Some(self.path()))
+    }
+
+    pub fn rev_parse(&self) -> WrapCommand {
+        rev_parse::rev_parse(Some(self.path()))
+    }
+
+    pub fn revert(&self) -> WrapCommand {
+        revert::revert(Some(self.path()))
+    }
+
+    pub fn stash(&self) -> WrapCommand {
+        stash::stash(Some(self.path()))
+    }
+
+    pub fn status(&self) -> WrapCommand {
+        status::status(Some(self.path()))
+    }
+
+    pub fn submodule(&self) -> WrapCommand {
+        submodule::submodule(Some(self.path()))
+    }
+
+    pub fn tag(&self) -> WrapCommand {
+        tag::tag(Some(self.path()))
+    }
+
+    pub fn worktree(&self) -> WrapCommand {
+        worktree::worktree(Some(self.path()))
+    }
+
+    /// Object name of the commit HEAD points to, None if the current branch has no commit yet
+    pub fn head(&self) -> Result<Option<String>, WrapError> {
+        let mut cmd = self.rev_parse();
+        cmd.option(rev_parse::quiet());
+        cmd.option(rev_parse::verify());
+        cmd.option(optionarg::simple("HEAD"));
+        quiet_query(cmd)
+    }
+
+    /// Short name of the current branch, None if HEAD is detached
+    pub fn current_branch(&self) -> Result<Option<String>, WrapError> {
+        let mut cmd = self.git("symbolic-ref");
+        cmd.option(optionarg::simple("--quiet"));
+        cmd.option(optionarg::simple("--short"));
+        cmd.option(optionarg::simple("HEAD"));
+        quiet_query(cmd)
+    }
+
+    /// The cherry-pick or revert in progress, see [sequencer_state]
+    pub fn sequencer_state(&self) -> io::Result<Option<SequencerState>> {
+        sequencer_state(self.git_dir())
+    }
+
+    /// True if tracked files have staged or unstaged changes; untracked files are not taken into account.
+    /// A bare repository is never dirty.
+    pub fn is_dirty(&self) -> Result<bool, WrapError> {
+        if self.bare {
+            return Ok(false);
+        }
+        let mut cmd = self.status();
+        cmd.option(status::porcelain("v2"));
+        cmd.option(status::null());
+        cmd.option(status::untracked_files("no"));
+        let output = cmd.execute_output()?;
+        Ok(!status::parse_porcelain_v2(output.stdout.as_str())?.entries.is_empty())
+    }
+}
+
+// runs a query whose `--quiet` mode exits with 1 and no message when there is no answer
+fn quiet_query(cmd: WrapCommand) -> Result<Option<String>, WrapError> {
+    match cmd.execute_output() {
+        Ok(output) => Ok(Some(String::from(output.stdout.trim_end_matches('\n')))),
+        Err(WrapError::ExitStatus(output)) if output.code == Some(1) && output.stderr.trim().is_empty() => Ok(None),
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,14 @@
+use crate::wrap_command::WrapCommand;
+use crate::git;
+
+mod options;
+pub use options::*;
+
+pub const GIT_COMMAND: &str = "revert";
+
+/// Revert some existing commits.
+/// Given one or more existing commits, revert the changes that the related patches introduce, and record some new commits that record them
+/// [Git doc](https://git-scm.com/docs/git-revert)
+pub fn revert(current_dir: Option<&str>) -> WrapCommand {
+    git(GIT_COMMAND, current_dir)
+}
@@ -0,0 +1,121 @@
+// Warning!! Code generated automatically: this file must not be edited by hand
+use crate::optionarg;
+use crate::wrap_command::FnOptionArg;
+
+pub const EDIT: &str = "--edit";
+pub const NO_EDIT: &str = "--no-edit";
+pub const CLEANUP: &str = "--cleanup";
+pub const MAINLINE: &str = "--mainline";
+pub const NO_COMMIT: &str = "--no-commit";
+pub const SIGNOFF: &str = "--signoff";
+pub const STRATEGY: &str = "--strategy";
+pub const STRATEGY_OPTION: &str = "--strategy-option";
+pub const RERERE_AUTOUPDATE: &str = "--rerere-autoupdate";
+pub const NO_RERERE_AUTOUPDATE: &str = "--no-rerere-autoupdate";
+pub const REFERENCE: &str = "--reference";
+pub const CONTINUE_REVERT: &str = "--continue";
+pub const SKIP: &str = "--skip";
+pub const QUIT: &str = "--quit";
+pub const ABORT: &str = "--abort";
+
+/// With this option, git revert will let you edit the commit message prior to committing the revert.
+/// -e, --edit
+pub fn edit() -> FnOptionArg {
+    optionarg::simple(EDIT)
+}
+
+/// With this option, git revert will not start the commit message editor.
+/// --no-edit
+pub fn no_edit() -> FnOptionArg {
+    optionarg::simple(NO_EDIT)
+}
+
+/// This option determines how the commit message will be cleaned up before being passed on to the commit machinery.
+/// --cleanup=<mode>
+pub fn cleanup(mode_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(CLEANUP, mode_arg)
+}
+
+/// Usually you cannot revert a merge because you do not know which side of the merge should be considered the mainline.
+/// This option specifies the parent number (starting from 1) of the mainline and allows revert to reverse the change relative to the specified parent.
+/// -m <parent-number>, --mainline <parent-number>
+pub fn mainline(parent_number_arg: &str) -> FnOptionArg {
+    optionarg::with_parameter(MAINLINE, parent_number_arg)
+}
+
+/// Usually the command automatically creates some commits with commit log messages stating which commits were reverted.
+/// This flag applies the changes necessary to revert the named commits to your working tree and the index, but does not make the commits.
+/// -n, --no-commit
+pub fn no_commit() -> FnOptionArg {
+    optionarg::simple(NO_COMMIT)
+}
+
+/// Add a Signed-off-by trailer at the end of the commit message.
+/// -s, --signoff
+pub fn signoff() -> FnOptionArg {
+    optionarg::simple(SIGNOFF)
+}
+
+/// Use the given merge strategy.
+/// Should only be used once.
+/// --strategy=<strategy>
+pub fn strategy(strategy_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(STRATEGY, strategy_arg)
+}
+
+/// Pass the merge strategy-specific option through to the merge strategy.
+/// -X<option>, --strategy-option=<option>
+pub fn strategy_option(option_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(STRATEGY_OPTION, option_arg)
+}
+
+/// After the rerere mechanism reuses a recorded resolution on the current conflict to update the files in the working tree, allow it to also update the index with the result of resolution.
+/// --rerere-autoupdate
+pub fn rerere_autoupdate() -> FnOptionArg {
+    optionarg::simple(RERERE_AUTOUPDATE)
+}
+
+/// Do not let rerere update the index with the result of resolution.
+/// --no-rerere-autoupdate
+pub fn no_rerere_autoupdate() -> FnOptionArg {
+    optionarg::simple(NO_RERERE_AUTOUPDATE)
+}
+
+/// Instead of starting the body of the log message with "This reverts <full-object-name-of-the-commit-being-reverted>.", refer to the commit using "--pretty=reference" format.
+/// --reference
+pub fn reference() -> FnOptionArg {
+    optionarg::simple(REFERENCE)
+}
+
+/// Continue the operation in progress using the information in .git/sequencer.
+/// Can be used to continue after resolving conflicts in a failed cherry-pick or revert.
+/// --continue
+pub fn continue_revert() -> FnOptionArg {
+    optionarg::simple(CONTINUE_REVERT)
+}
+
+/// Skip the current commit and continue with the rest of the sequence.
+/// --skip
+pub fn skip() -> FnOptionArg {
+    optionarg::simple(SKIP)
+}
+
+/// Forget about the current operation in progress.
+/// Can be used to clear the sequencer state after a failed cherry-pick or revert.
+/// --quit
+pub fn quit() -> FnOptionArg {
+    optionarg::simple(QUIT)
+}
+
+/// Cancel the operation and return to the pre-sequence state.
+/// --abort
+pub fn abort() -> FnOptionArg {
+    optionarg::simple(ABORT)
+}
+
+/// Commits to revert.
+/// Ranges such as <commit>..<commit> are accepted.
+/// <commit>
+pub fn commit(commit: &str) -> FnOptionArg {
+    optionarg::value_parameter(commit)
+}
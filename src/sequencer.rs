@@ -0,0 +1,92 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+const CHERRY_PICK_HEAD: &str = "CHERRY_PICK_HEAD";
+const REVERT_HEAD: &str = "REVERT_HEAD";
+const SEQUENCER_TODO: &str = "sequencer/todo";
+
+/// The sequencer operation in progress
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SequencerOperation {
+    CherryPick,
+    Revert,
+}
+
+/// A commit the sequencer has still to apply, as read from `.git/sequencer/todo`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SequencerStep {
+    /// `pick` or `revert`
+    pub action: String,
+    /// Abbreviated object name of the commit
+    pub oid: String,
+    pub subject: String,
+}
+
+/// State of a cherry-pick or revert stopped by a conflict, an empty commit or `--no-commit`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SequencerState {
+    pub operation: SequencerOperation,
+    /// The commit being applied, read from CHERRY_PICK_HEAD or REVERT_HEAD.
+    /// None once it has been committed or skipped while further commits remain.
+    pub current: Option<String>,
+    /// Commits left to apply when several were given, the one being applied first
+    pub todo: Vec<SequencerStep>,
+}
+
+/// Reports the cherry-pick or revert in progress in the repository whose git dir is [git_dir]
+/// (see [crate::Repository::git_dir]), None if there is none.
+/// Inspects CHERRY_PICK_HEAD, REVERT_HEAD and the todo list of the `sequencer` directory.
+pub fn sequencer_state(git_dir: &str) -> io::Result<Option<SequencerState>> {
+    let git_dir = Path::new(git_dir);
+    let cherry_pick_head = read_head(&git_dir.join(CHERRY_PICK_HEAD))?;
+    let revert_head = read_head(&git_dir.join(REVERT_HEAD))?;
+    let todo = match fs::read_to_string(git_dir.join(SEQUENCER_TODO)) {
+        Ok(todo) => parse_todo(todo.as_str()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Vec::new(),
+        Err(e) => return Err(e),
+    };
+
+    let operation = if cherry_pick_head.is_some() {
+        SequencerOperation::CherryPick
+    } else if revert_head.is_some() {
+        SequencerOperation::Revert
+    } else if let Some(step) = todo.first() {
+        match step.action.as_str() {
+            "revert" | "r" => SequencerOperation::Revert,
+            _ => SequencerOperation::CherryPick,
+        }
+    } else {
+        return Ok(None);
+    };
+
+    Ok(Some(SequencerState {
+        operation,
+        current: cherry_pick_head.or(revert_head),
+        todo,
+    }))
+}
+
+fn read_head(path: &Path) -> io::Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(oid) => Ok(Some(String::from(oid.trim()))),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+// <action> <oid> <subject>, comments and blank lines ignored
+fn parse_todo(todo: &str) -> Vec<SequencerStep> {
+    todo.lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .map(|l| {
+            let mut f = l.splitn(3, ' ');
+            SequencerStep {
+                action: String::from(f.next().unwrap_or_default()),
+                oid: String::from(f.next().unwrap_or_default()),
+                subject: String::from(f.next().unwrap_or_default()),
+            }
+        })
+        .collect()
+}
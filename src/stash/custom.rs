@@ -0,0 +1,60 @@
+use crate::optionarg;
+use crate::wrap_command::FnOptionArg;
+
+pub const PUSH: &str = "push";
+pub const LIST: &str = "list";
+pub const SHOW: &str = "show";
+pub const APPLY: &str = "apply";
+pub const POP: &str = "pop";
+pub const DROP: &str = "drop";
+pub const BRANCH: &str = "branch";
+pub const CLEAR: &str = "clear";
+
+/// Save your local modifications to a new stash entry and roll them back to HEAD.
+/// push
+pub fn push() -> FnOptionArg {
+    optionarg::simple(PUSH)
+}
+
+/// List the stash entries that you currently have, as stash@{<n>}: <subject>.
+/// list
+pub fn list() -> FnOptionArg {
+    optionarg::simple(LIST)
+}
+
+/// Show the changes recorded in the stash entry as a diff between the stashed contents and the commit back when the stash entry was first created.
+/// show
+pub fn show() -> FnOptionArg {
+    optionarg::simple(SHOW)
+}
+
+/// Like pop, but do not remove the state from the stash list.
+/// apply
+pub fn apply() -> FnOptionArg {
+    optionarg::simple(APPLY)
+}
+
+/// Remove a single stashed state from the stash list and apply it on top of the current working tree state.
+/// pop
+pub fn pop() -> FnOptionArg {
+    optionarg::simple(POP)
+}
+
+/// Remove a single stash entry from the list of stash entries.
+/// drop
+pub fn drop() -> FnOptionArg {
+    optionarg::simple(DROP)
+}
+
+/// Creates and checks out a new branch starting from the commit at which the stash entry was originally created,
+/// applies the changes recorded in it and drops it if that succeeds.
+/// branch <branchname>
+pub fn branch(branchname: &str) -> FnOptionArg {
+    optionarg::with_parameter(BRANCH, branchname)
+}
+
+/// Remove all the stash entries.
+/// clear
+pub fn clear() -> FnOptionArg {
+    optionarg::simple(CLEAR)
+}
@@ -0,0 +1,21 @@
+use crate::wrap_command::WrapCommand;
+use crate::git;
+
+mod options;
+pub use options::*;
+
+mod custom;
+pub use custom::*;
+
+mod parse;
+pub use parse::*;
+
+pub const GIT_COMMAND: &str = "stash";
+
+/// Stash the changes in a dirty working directory away.
+/// Use git stash when you want to record the current state of the working directory and the index, but want to go back to a clean working directory.
+/// The subcommand is given as the first option, e.g. stash::push()
+/// [Git doc](https://git-scm.com/docs/git-stash)
+pub fn stash(current_dir: Option<&str>) -> WrapCommand {
+    git(GIT_COMMAND, current_dir)
+}
@@ -0,0 +1,111 @@
+// Warning!! Code generated automatically: this file must not be edited by hand
+use crate::optionarg;
+use crate::wrap_command::FnOptionArg;
+
+pub const MESSAGE: &str = "--message";
+pub const INCLUDE_UNTRACKED: &str = "--include-untracked";
+pub const ALL: &str = "--all";
+pub const KEEP_INDEX: &str = "--keep-index";
+pub const NO_KEEP_INDEX: &str = "--no-keep-index";
+pub const STAGED: &str = "--staged";
+pub const PATCH: &str = "--patch";
+pub const STAT: &str = "--stat";
+pub const INDEX: &str = "--index";
+pub const QUIET: &str = "--quiet";
+pub const PATHSPEC_FROM_FILE: &str = "--pathspec-from-file";
+pub const PATHSPEC_FILE_NUL: &str = "--pathspec-file-nul";
+pub const HYPHEN_HYPHEN: &str = "--";
+
+/// Give the stash entry a description (push).
+/// -m <message>, --message=<message>
+pub fn message(message_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(MESSAGE, message_arg)
+}
+
+/// All untracked files are also stashed and then cleaned up with git clean (push).
+/// When used with show, show the untracked files in the stash entry as part of the diff.
+/// -u, --include-untracked
+pub fn include_untracked() -> FnOptionArg {
+    optionarg::simple(INCLUDE_UNTRACKED)
+}
+
+/// All ignored and untracked files are also stashed and then cleaned up with git clean (push).
+/// -a, --all
+pub fn all() -> FnOptionArg {
+    optionarg::simple(ALL)
+}
+
+/// All changes already added to the index are left intact (push).
+/// -k, --keep-index
+pub fn keep_index() -> FnOptionArg {
+    optionarg::simple(KEEP_INDEX)
+}
+
+/// Changes already added to the index are stashed and removed from the index as well (push).
+/// --no-keep-index
+pub fn no_keep_index() -> FnOptionArg {
+    optionarg::simple(NO_KEEP_INDEX)
+}
+
+/// Stash only the changes that are currently staged (push).
+/// -S, --staged
+pub fn staged() -> FnOptionArg {
+    optionarg::simple(STAGED)
+}
+
+/// Interactively select hunks from the diff between HEAD and the working tree to be stashed (push).
+/// When used with show, show the changes recorded in the stash entry in patch form.
+/// -p, --patch
+pub fn patch() -> FnOptionArg {
+    optionarg::simple(PATCH)
+}
+
+/// Show the changes recorded in the stash entry as a diffstat (show).
+/// --stat
+pub fn stat() -> FnOptionArg {
+    optionarg::simple(STAT)
+}
+
+/// Try to reinstate not only the working tree’s changes, but also the index’s ones (apply, pop).
+/// --index
+pub fn index() -> FnOptionArg {
+    optionarg::simple(INDEX)
+}
+
+/// Quiet, suppress feedback messages.
+/// -q, --quiet
+pub fn quiet() -> FnOptionArg {
+    optionarg::simple(QUIET)
+}
+
+/// Pathspec is passed in <file> instead of commandline args (push).
+/// --pathspec-from-file=<file>
+pub fn pathspec_from_file(file_arg: &str) -> FnOptionArg {
+    optionarg::equal_no_optional(PATHSPEC_FROM_FILE, file_arg)
+}
+
+/// Only meaningful with --pathspec-from-file.
+/// Pathspec elements are separated with NUL character and all other characters are taken literally.
+/// --pathspec-file-nul
+pub fn pathspec_file_nul() -> FnOptionArg {
+    optionarg::simple(PATHSPEC_FILE_NUL)
+}
+
+/// A reference of the form stash@{<revision>} (show, apply, pop, drop, branch).
+/// When no <stash> is given, the latest stash is assumed (that is, stash@{0}).
+/// <stash>
+pub fn stash_ref(stash: &str) -> FnOptionArg {
+    optionarg::value_parameter(stash)
+}
+
+/// Separates pathspec from options for the purpose of disambiguation.
+/// --
+pub fn hyphen_hyphen() -> FnOptionArg {
+    optionarg::simple(HYPHEN_HYPHEN)
+}
+
+/// Limits the paths affected by push: only the matching paths are stashed.
+/// <pathspec>
+pub fn pathspec(pathspec: &str) -> FnOptionArg {
+    optionarg::value_parameter(pathspec)
+}
@@ -0,0 +1,44 @@
+use crate::parser::{parse_error, records};
+use crate::WrapError;
+
+/// A stash entry, as listed by `git stash list`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StashEntry {
+    /// Position in the stash list, 0 being the latest entry
+    pub index: usize,
+    /// Reference naming the entry, e.g. `stash@{0}`
+    pub reference: String,
+    /// Branch the entry was created on, None if HEAD was detached or the entry has no branch (e.g. autostash)
+    pub branch: Option<String>,
+    /// The stash message, or the subject of the HEAD commit for entries created without one
+    pub message: String,
+}
+
+/// Parses the output of `git stash list`, with or without `-z`, e.g.
+/// `stash@{0}: WIP on main: 1234abc subject` or `stash@{1}: On main: message`
+pub fn parse_stash_list(output: &str) -> Result<Vec<StashEntry>, WrapError> {
+    records(output).into_iter().map(parse_entry).collect()
+}
+
+fn parse_entry(line: &str) -> Result<StashEntry, WrapError> {
+    let (reference, subject) = line.split_once(": ").unwrap_or((line, ""));
+    let index = reference.strip_prefix("stash@{")
+        .and_then(|r| r.strip_suffix('}'))
+        .and_then(|n| n.parse().ok())
+        .ok_or_else(|| parse_error("stash entry", line))?;
+
+    let (branch, message) = match subject.strip_prefix("WIP on ").or(subject.strip_prefix("On ")) {
+        Some(rest) => {
+            let (branch, message) = rest.split_once(": ").unwrap_or((rest, ""));
+            ((branch != "(no branch)").then(|| String::from(branch)), message)
+        }
+        None => (None, subject),
+    };
+
+    Ok(StashEntry {
+        index,
+        reference: String::from(reference),
+        branch,
+        message: String::from(message),
+    })
+}
@@ -3,6 +3,8 @@ use crate::git;
 
 mod options;
 pub use options::*;
+mod parse;
+pub use parse::*;
 
 pub const GIT_COMMAND: &str = "status";
 
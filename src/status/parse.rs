@@ -0,0 +1,224 @@
+use crate::parser::{is_nul_terminated, parse_error, parse_mode, records, unquote_path};
+use crate::WrapError;
+
+/// The output of `git status --porcelain=v2`, see [parse_porcelain_v2]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StatusV2 {
+    /// Branch headers, only filled in when `--branch` is given
+    pub branch: BranchStatus,
+    /// Number of stash entries, only filled in when `--show-stash` is given
+    pub stash: Option<u32>,
+    pub entries: Vec<StatusEntry>,
+}
+
+/// The `# branch.*` headers of `git status --porcelain=v2 --branch`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BranchStatus {
+    /// Current commit, None on an initial commit
+    pub oid: Option<String>,
+    /// Current branch, None if HEAD is detached
+    pub head: Option<String>,
+    pub upstream: Option<String>,
+    /// Ahead/behind counts, only present if the upstream exists
+    pub ahead: Option<u32>,
+    pub behind: Option<u32>,
+}
+
+/// Index and worktree status codes of an entry, e.g. `M` and `.` for a staged modification
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StatusCode {
+    pub index: char,
+    pub worktree: char,
+}
+
+/// A changed path, as reported by `git status --porcelain=v2`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StatusEntry {
+    /// `1` entries: ordinary changed paths
+    Changed {
+        code: StatusCode,
+        /// Submodule state: `N...` for regular files, `S<c><m><u>` for submodules
+        submodule: String,
+        mode_head: u32,
+        mode_index: u32,
+        mode_worktree: u32,
+        oid_head: String,
+        oid_index: String,
+        path: String,
+    },
+    /// `2` entries: renamed or copied paths
+    RenamedOrCopied {
+        code: StatusCode,
+        submodule: String,
+        mode_head: u32,
+        mode_index: u32,
+        mode_worktree: u32,
+        oid_head: String,
+        oid_index: String,
+        /// `R` for a rename, `C` for a copy
+        kind: char,
+        /// Similarity percentage between the source and the target
+        score: u8,
+        path: String,
+        orig_path: String,
+    },
+    /// `u` entries: unmerged paths, with the modes and object names of the three stages
+    Unmerged {
+        code: StatusCode,
+        submodule: String,
+        mode_stage1: u32,
+        mode_stage2: u32,
+        mode_stage3: u32,
+        mode_worktree: u32,
+        oid_stage1: String,
+        oid_stage2: String,
+        oid_stage3: String,
+        path: String,
+    },
+    Untracked(String),
+    Ignored(String),
+}
+
+impl StatusEntry {
+    /// The path of the entry (the target path for renames and copies)
+    pub fn path(&self) -> &str {
+        match self {
+            StatusEntry::Changed { path, .. } |
+            StatusEntry::RenamedOrCopied { path, .. } |
+            StatusEntry::Unmerged { path, .. } |
+            StatusEntry::Untracked(path) |
+            StatusEntry::Ignored(path) => path,
+        }
+    }
+}
+
+/// Parses the output of `git status --porcelain=v2`, optionally run with `--branch`, `--show-stash` and `-z`.
+/// Paths quoted by git are unquoted.
+pub fn parse_porcelain_v2(output: &str) -> Result<StatusV2, WrapError> {
+    let nul = is_nul_terminated(output);
+    let mut status = StatusV2::default();
+    let mut records = records(output).into_iter();
+
+    while let Some(record) = records.next() {
+        let (kind, rest) = record.split_once(' ').ok_or_else(|| parse_error("status entry", record))?;
+        match kind {
+            "#" => parse_header(&mut status, rest)?,
+            "1" => status.entries.push(parse_changed(rest, nul)?),
+            "2" => {
+                let orig_path = if nul { records.next().map(String::from) } else { None };
+                status.entries.push(parse_renamed(rest, orig_path)?);
+            }
+            "u" => status.entries.push(parse_unmerged(rest, nul)?),
+            "?" => status.entries.push(StatusEntry::Untracked(path(rest, nul))),
+            "!" => status.entries.push(StatusEntry::Ignored(path(rest, nul))),
+            _ => return Err(parse_error("status entry", record)),
+        }
+    }
+
+    Ok(status)
+}
+
+fn parse_header(status: &mut StatusV2, header: &str) -> Result<(), WrapError> {
+    let (name, value) = header.split_once(' ').unwrap_or((header, ""));
+    match name {
+        "branch.oid" => status.branch.oid = (value != "(initial)").then(|| String::from(value)),
+        "branch.head" => status.branch.head = (value != "(detached)").then(|| String::from(value)),
+        "branch.upstream" => status.branch.upstream = Some(String::from(value)),
+        "branch.ab" => {
+            let (ahead, behind) = value.split_once(' ').ok_or_else(|| parse_error("branch.ab header", value))?;
+            let count = |c: &str, sign: char| c.strip_prefix(sign).and_then(|n| n.parse::<u32>().ok())
+                .ok_or_else(|| parse_error("branch.ab header", value));
+            status.branch.ahead = Some(count(ahead, '+')?);
+            status.branch.behind = Some(count(behind, '-')?);
+        }
+        "stash" => status.stash = Some(value.parse().map_err(|_| parse_error("stash header", value))?),
+        // unknown headers are ignored, as required by the porcelain v2 format
+        _ => {}
+    }
+    Ok(())
+}
+
+fn fields(entry: &str, count: usize) -> Result<Vec<&str>, WrapError> {
+    let fields: Vec<&str> = entry.splitn(count, ' ').collect();
+    if fields.len() == count {
+        Ok(fields)
+    } else {
+        Err(parse_error("status entry", entry))
+    }
+}
+
+fn code(xy: &str) -> Result<StatusCode, WrapError> {
+    let mut c = xy.chars();
+    match (c.next(), c.next(), c.next()) {
+        (Some(index), Some(worktree), None) => Ok(StatusCode { index, worktree }),
+        _ => Err(parse_error("status code", xy)),
+    }
+}
+
+fn path(path: &str, nul: bool) -> String {
+    if nul {
+        String::from(path)
+    } else {
+        unquote_path(path)
+    }
+}
+
+// 1 <XY> <sub> <mH> <mI> <mW> <hH> <hI> <path>
+fn parse_changed(entry: &str, nul: bool) -> Result<StatusEntry, WrapError> {
+    let f = fields(entry, 8)?;
+    Ok(StatusEntry::Changed {
+        code: code(f[0])?,
+        submodule: String::from(f[1]),
+        mode_head: parse_mode(f[2])?,
+        mode_index: parse_mode(f[3])?,
+        mode_worktree: parse_mode(f[4])?,
+        oid_head: String::from(f[5]),
+        oid_index: String::from(f[6]),
+        path: path(f[7], nul),
+    })
+}
+
+// 2 <XY> <sub> <mH> <mI> <mW> <hH> <hI> <X><score> <path><sep><origPath>
+fn parse_renamed(entry: &str, orig_path: Option<String>) -> Result<StatusEntry, WrapError> {
+    let f = fields(entry, 9)?;
+    let mut score = f[7].chars();
+    let kind = score.next().ok_or_else(|| parse_error("rename score", f[7]))?;
+    let score = score.as_str().parse::<u8>().map_err(|_| parse_error("rename score", f[7]))?;
+    let (target, orig_path) = match orig_path {
+        Some(orig) => (String::from(f[8]), orig),
+        None => {
+            let (target, orig) = f[8].split_once('\t').ok_or_else(|| parse_error("rename paths", f[8]))?;
+            (unquote_path(target), unquote_path(orig))
+        }
+    };
+    Ok(StatusEntry::RenamedOrCopied {
+        code: code(f[0])?,
+        submodule: String::from(f[1]),
+        mode_head: parse_mode(f[2])?,
+        mode_index: parse_mode(f[3])?,
+        mode_worktree: parse_mode(f[4])?,
+        oid_head: String::from(f[5]),
+        oid_index: String::from(f[6]),
+        kind,
+        score,
+        path: target,
+        orig_path,
+    })
+}
+
+// u <XY> <sub> <m1> <m2> <m3> <mW> <h1> <h2> <h3> <path>
+fn parse_unmerged(entry: &str, nul: bool) -> Result<StatusEntry, WrapError> {
+    let f = fields(entry, 10)?;
+    Ok(StatusEntry::Unmerged {
+        code: code(f[0])?,
+        submodule: String::from(f[1]),
+        mode_stage1: parse_mode(f[2])?,
+        mode_stage2: parse_mode(f[3])?,
+        mode_stage3: parse_mode(f[4])?,
+        mode_worktree: parse_mode(f[5])?,
+        oid_stage1: String::from(f[6]),
+        oid_stage2: String::from(f[7]),
+        oid_stage3: String::from(f[8]),
+        path: path(f[9], nul),
+    })
+}
@@ -0,0 +1,82 @@
+use crate::optionarg;
+use crate::wrap_command::FnOptionArg;
+
+pub const ADD: &str = "add";
+pub const STATUS: &str = "status";
+pub const INIT_SUBMODULE: &str = "init";
+pub const DEINIT: &str = "deinit";
+pub const UPDATE: &str = "update";
+pub const SET_BRANCH: &str = "set-branch";
+pub const SET_URL: &str = "set-url";
+pub const SUMMARY: &str = "summary";
+pub const FOREACH: &str = "foreach";
+pub const SYNC: &str = "sync";
+pub const ABSORBGITDIRS: &str = "absorbgitdirs";
+
+/// Add the given repository as a submodule at the given path to the changeset to be committed next to the current project.
+/// add
+pub fn add() -> FnOptionArg {
+    optionarg::simple(ADD)
+}
+
+/// Show the status of the submodules.
+/// This will print the SHA-1 of the currently checked out commit for each submodule, along with the submodule path and the output of git describe for the SHA-1.
+/// status
+pub fn status() -> FnOptionArg {
+    optionarg::simple(STATUS)
+}
+
+/// Initialize the submodules recorded in the index (which were added and committed elsewhere) by setting submodule.$name.url in .git/config.
+/// init
+pub fn init_submodule() -> FnOptionArg {
+    optionarg::simple(INIT_SUBMODULE)
+}
+
+/// Unregister the given submodules, i.e. remove the whole submodule.$name section from .git/config together with their work tree.
+/// deinit
+pub fn deinit() -> FnOptionArg {
+    optionarg::simple(DEINIT)
+}
+
+/// Update the registered submodules to match what the superproject expects by cloning missing submodules, fetching missing commits in submodules and updating the working tree of the submodules.
+/// update
+pub fn update() -> FnOptionArg {
+    optionarg::simple(UPDATE)
+}
+
+/// Sets the default remote tracking branch for the submodule.
+/// set-branch
+pub fn set_branch() -> FnOptionArg {
+    optionarg::simple(SET_BRANCH)
+}
+
+/// Sets the URL of the specified submodule to <newurl>.
+/// Then, it will automatically synchronize the submodule’s new remote URL configuration.
+/// set-url
+pub fn set_url() -> FnOptionArg {
+    optionarg::simple(SET_URL)
+}
+
+/// Show commit summary between the given commit (defaults to HEAD) and working tree/index.
+/// summary
+pub fn summary() -> FnOptionArg {
+    optionarg::simple(SUMMARY)
+}
+
+/// Evaluates an arbitrary shell command in each checked out submodule.
+/// foreach
+pub fn foreach() -> FnOptionArg {
+    optionarg::simple(FOREACH)
+}
+
+/// Synchronizes submodules' remote URL configuration setting to the value specified in .gitmodules.
+/// sync
+pub fn sync() -> FnOptionArg {
+    optionarg::simple(SYNC)
+}
+
+/// Move the git directory of submodules into its superproject’s $GIT_DIR/modules path and then connect the git directory and its working directory by setting the core.worktree and adding a .git file pointing to the git directory embedded in the superprojects git directory.
+/// absorbgitdirs
+pub fn absorbgitdirs() -> FnOptionArg {
+    optionarg::simple(ABSORBGITDIRS)
+}
@@ -0,0 +1,20 @@
+use crate::wrap_command::WrapCommand;
+use crate::git;
+
+mod options;
+pub use options::*;
+
+mod custom;
+pub use custom::*;
+
+mod parse;
+pub use parse::*;
+
+pub const GIT_COMMAND: &str = "submodule";
+
+/// Initialize, update or inspect submodules.
+/// The subcommand is given as the first option, e.g. submodule::update()
+/// [Git doc](https://git-scm.com/docs/git-submodule)
+pub fn submodule(current_dir: Option<&str>) -> WrapCommand {
+    git(GIT_COMMAND, current_dir)
+}
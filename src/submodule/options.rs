@@ -0,0 +1,220 @@
+// Warning!! Code generated automatically: this file must not be edited by hand
+use crate::optionarg;
+use crate::wrap_command::FnOptionArg;
+
+pub const QUIET: &str = "--quiet";
+pub const PROGRESS: &str = "--progress";
+pub const ALL: &str = "--all";
+pub const BRANCH: &str = "--branch";
+pub const DEFAULT: &str = "--default";
+pub const FORCE: &str = "--force";
+pub const CACHED: &str = "--cached";
+pub const INIT: &str = "--init";
+pub const REMOTE: &str = "--remote";
+pub const NO_FETCH: &str = "--no-fetch";
+pub const CHECKOUT: &str = "--checkout";
+pub const MERGE: &str = "--merge";
+pub const REBASE: &str = "--rebase";
+pub const REFERENCE: &str = "--reference";
+pub const RECURSIVE: &str = "--recursive";
+pub const DEPTH: &str = "--depth";
+pub const RECOMMEND_SHALLOW: &str = "--recommend-shallow";
+pub const NO_RECOMMEND_SHALLOW: &str = "--no-recommend-shallow";
+pub const JOBS: &str = "--jobs";
+pub const SINGLE_BRANCH: &str = "--single-branch";
+pub const NO_SINGLE_BRANCH: &str = "--no-single-branch";
+pub const FILTER: &str = "--filter";
+pub const NAME: &str = "--name";
+pub const HYPHEN_HYPHEN: &str = "--";
+
+/// Only print error messages.
+/// -q, --quiet
+pub fn quiet() -> FnOptionArg {
+    optionarg::simple(QUIET)
+}
+
+/// This option is only valid for add and update commands.
+/// Progress status is reported on the standard error stream by default when it is attached to a terminal, unless -q is specified.
+/// --progress
+pub fn progress() -> FnOptionArg {
+    optionarg::simple(PROGRESS)
+}
+
+/// This option is only valid for the deinit command.
+/// Unregister all submodules in the working tree.
+/// --all
+pub fn all() -> FnOptionArg {
+    optionarg::simple(ALL)
+}
+
+/// Branch of repository to add as submodule (add), or branch to track (set-branch).
+/// -b, --branch <branch>
+pub fn branch(branch_arg: &str) -> FnOptionArg {
+    optionarg::with_parameter(BRANCH, branch_arg)
+}
+
+/// With set-branch, remove the branch configuration so the remote HEAD is tracked.
+/// -d, --default
+pub fn default() -> FnOptionArg {
+    optionarg::simple(DEFAULT)
+}
+
+/// This option is only valid for add, deinit and update commands.
+/// When running add, allow adding an otherwise ignored submodule path.
+/// When running deinit the submodule working trees will be removed even if they contain local changes.
+/// When running update, throw away local changes in submodules when switching to a different commit.
+/// -f, --force
+pub fn force() -> FnOptionArg {
+    optionarg::simple(FORCE)
+}
+
+/// This option is only valid for status and summary commands.
+/// These commands typically use the commit found in the submodule HEAD, but with this option, the commit stored in the index is used instead.
+/// --cached
+pub fn cached() -> FnOptionArg {
+    optionarg::simple(CACHED)
+}
+
+/// This option is only valid for the update command.
+/// Initialize all submodules for which "git submodule init" has not been called so far before updating.
+/// --init
+pub fn init() -> FnOptionArg {
+    optionarg::simple(INIT)
+}
+
+/// This option is only valid for the update command.
+/// Instead of using the superproject’s recorded SHA-1 to update the submodule, use the status of the submodule’s remote-tracking branch.
+/// --remote
+pub fn remote() -> FnOptionArg {
+    optionarg::simple(REMOTE)
+}
+
+/// This option is only valid for the update command.
+/// Don’t fetch new objects from the remote site.
+/// -N, --no-fetch
+pub fn no_fetch() -> FnOptionArg {
+    optionarg::simple(NO_FETCH)
+}
+
+/// This option is only valid for the update command.
+/// Checkout the commit recorded in the superproject on a detached HEAD in the submodule.
+/// --checkout
+pub fn checkout() -> FnOptionArg {
+    optionarg::simple(CHECKOUT)
+}
+
+/// This option is only valid for the update command.
+/// Merge the commit recorded in the superproject into the current branch of the submodule.
+/// --merge
+pub fn merge() -> FnOptionArg {
+    optionarg::simple(MERGE)
+}
+
+/// This option is only valid for the update command.
+/// Rebase the current branch onto the commit recorded in the superproject.
+/// --rebase
+pub fn rebase() -> FnOptionArg {
+    optionarg::simple(REBASE)
+}
+
+/// This option is only valid for add and update commands.
+/// These commands sometimes need to clone a remote repository.
+/// In this case, this option will be passed to the git-clone(1) command.
+/// --reference <repository>
+pub fn reference(repository_arg: &str) -> FnOptionArg {
+    optionarg::with_parameter(REFERENCE, repository_arg)
+}
+
+/// This option is only valid for foreach, update, status and sync commands.
+/// Traverse submodules recursively.
+/// --recursive
+pub fn recursive() -> FnOptionArg {
+    optionarg::simple(RECURSIVE)
+}
+
+/// This option is valid for add and update commands.
+/// Create a shallow clone with a history truncated to the specified number of revisions.
+/// --depth <depth>
+pub fn depth(depth_arg: &str) -> FnOptionArg {
+    optionarg::with_parameter(DEPTH, depth_arg)
+}
+
+/// This option is only valid for the update command.
+/// The initial clone of a submodule will use the recommended submodule.<name>.shallow as provided by the .gitmodules file by default.
+/// --recommend-shallow
+pub fn recommend_shallow() -> FnOptionArg {
+    optionarg::simple(RECOMMEND_SHALLOW)
+}
+
+/// This option is only valid for the update command.
+/// Ignore the recommended submodule.<name>.shallow of the .gitmodules file.
+/// --no-recommend-shallow
+pub fn no_recommend_shallow() -> FnOptionArg {
+    optionarg::simple(NO_RECOMMEND_SHALLOW)
+}
+
+/// This option is only valid for the update command.
+/// Clone new submodules in parallel with as many jobs.
+/// -j <n>, --jobs <n>
+pub fn jobs(n_arg: &str) -> FnOptionArg {
+    optionarg::with_parameter(JOBS, n_arg)
+}
+
+/// This option is only valid for the update command.
+/// Clone only one branch during update: HEAD or one specified by --branch.
+/// --single-branch
+pub fn single_branch() -> FnOptionArg {
+    optionarg::simple(SINGLE_BRANCH)
+}
+
+/// This option is only valid for the update command.
+/// Clone all branches during update.
+/// --no-single-branch
+pub fn no_single_branch() -> FnOptionArg {
+    optionarg::simple(NO_SINGLE_BRANCH)
+}
+
+/// This option is only valid for the update command.
+/// Use the partial clone feature and request that the server sends a subset of reachable objects.
+/// --filter <filter-spec>
+pub fn filter(filter_spec_arg: &str) -> FnOptionArg {
+    optionarg::with_parameter(FILTER, filter_spec_arg)
+}
+
+/// This option is only valid for the add command.
+/// It sets the submodule’s name to the given string instead of defaulting to its path.
+/// --name <name>
+pub fn name(name_arg: &str) -> FnOptionArg {
+    optionarg::with_parameter(NAME, name_arg)
+}
+
+/// Do not interpret any more arguments as options.
+/// --
+pub fn hyphen_hyphen() -> FnOptionArg {
+    optionarg::simple(HYPHEN_HYPHEN)
+}
+
+/// URL of the repository to add as a submodule (add).
+/// <repository>
+pub fn repository(repository: &str) -> FnOptionArg {
+    optionarg::value_parameter(repository)
+}
+
+/// Paths to submodule(s).
+/// When specified this will restrict the command to only operate on the submodules found at the specified paths.
+/// <path>
+pub fn path(path: &str) -> FnOptionArg {
+    optionarg::value_parameter(path)
+}
+
+/// New URL of the submodule (set-url).
+/// <newurl>
+pub fn new_url(newurl: &str) -> FnOptionArg {
+    optionarg::value_parameter(newurl)
+}
+
+/// Shell command evaluated in each checked out submodule (foreach).
+/// <command>
+pub fn command(command: &str) -> FnOptionArg {
+    optionarg::value_parameter(command)
+}
@@ -0,0 +1,54 @@
+use crate::parser::{parse_error, records};
+use crate::WrapError;
+
+/// State of a submodule, given by the first character of a `git submodule status` line
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SubmoduleState {
+    /// The checked out commit matches the one recorded in the superproject
+    UpToDate,
+    /// `-`: the submodule is not initialized
+    Uninitialized,
+    /// `+`: the checked out commit does not match the one recorded in the superproject
+    Modified,
+    /// `U`: the submodule has merge conflicts
+    MergeConflict,
+}
+
+/// A submodule, as reported by `git submodule status`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SubmoduleStatus {
+    pub state: SubmoduleState,
+    /// Commit checked out in the submodule (or recorded in the superproject, if not checked out)
+    pub sha: String,
+    pub path: String,
+    /// Output of git describe for the commit, e.g. `heads/main` or `v1.0-2-g1234abc`
+    pub describe: Option<String>,
+}
+
+/// Parses the output of `git submodule status`, e.g. `+1234abc... lib/dep (v1.0-2-g1234abc)`
+pub fn parse_submodule_status(output: &str) -> Result<Vec<SubmoduleStatus>, WrapError> {
+    records(output).into_iter().map(parse_entry).collect()
+}
+
+fn parse_entry(line: &str) -> Result<SubmoduleStatus, WrapError> {
+    let mut chars = line.chars();
+    let state = match chars.next() {
+        Some(' ') => SubmoduleState::UpToDate,
+        Some('-') => SubmoduleState::Uninitialized,
+        Some('+') => SubmoduleState::Modified,
+        Some('U') => SubmoduleState::MergeConflict,
+        _ => return Err(parse_error("submodule status", line)),
+    };
+    let (sha, rest) = chars.as_str().split_once(' ').ok_or_else(|| parse_error("submodule status", line))?;
+    let (path, describe) = match rest.strip_suffix(')').and_then(|r| r.rsplit_once(" (")) {
+        Some((path, describe)) => (path, Some(String::from(describe))),
+        None => (rest, None),
+    };
+
+    Ok(SubmoduleStatus {
+        state,
+        sha: String::from(sha),
+        path: String::from(path),
+        describe,
+    })
+}
@@ -1,7 +1,12 @@
-use crate::{clone, config, rev_parse, git};
+use crate::{add, bisect, blame, cat_file, checkout, cherry_pick, clone, commit, config, diff, for_each_ref, global, init, log, ls_tree, rebase, remote, rev_parse, revert, stash, status, submodule, worktree, git, GitVersion, Repository, SequencerOperation, WrapError};
+use crate::executor::{set_thread_executor, CassetteExecutor, MockExecutor};
+use std::sync::Arc;
 use std::fs;
-use std::time::{SystemTime, UNIX_EPOCH};
-use crate::wrap_command::WrapCommand;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use crate::wrap_command::{Stdin, WrapCommand, WrapOutput};
+use crate::wrap_stream::{parse_progress, StreamLine};
 
 const REPO_CONFIG_EMAIL: &str = "test@email.com";
 const REPO_URL: &str = "https://github.com/japiber/gitwrap.git";
@@ -92,6 +97,1474 @@ fn test_config() {
     fs::remove_dir_all(path.as_str()).unwrap();
 }
 
+#[test]
+fn test_global_options() {
+    let path = gitwrap_test_path();
+    fs::create_dir(path.as_str()).unwrap();
+    init_repo_with_commit(&path);
+
+    {
+        let cmd_set = format!("git -C {path} -c core.abbrev=12 --no-pager --no-optional-locks rev-parse --short --default HEAD");
+        let cmd = rev_parse!(global: [
+                global::directory(path.as_str()),
+                global::config("core.abbrev", "12"),
+                global::no_pager(),
+                global::no_optional_locks()],
+            None,
+            rev_parse::short(""),
+            rev_parse::default("HEAD"));
+        assert_eq!(cmd.dry_run().unwrap(), cmd_set);
+
+        let r = cmd.execute().unwrap();
+        assert_eq!(r.trim().len(), 12);
+    }
+
+    {
+        let mut cmd = rev_parse::rev_parse(None);
+        cmd.global_option(global::git_dir(format!("{path}/.git").as_str()));
+        cmd.option(rev_parse::is_bare_repository());
+        assert!(cmd.dry_run().unwrap().starts_with("git --git-dir="));
+        assert!(cmd.execute().unwrap().contains("false"));
+    }
+
+    fs::remove_dir_all(path.as_str()).unwrap();
+}
+
+#[test]
+fn test_dry_run_quoting() {
+    let mut cmd = commit!(Some("/tmp"),
+        commit::message("fix: a b"),
+        commit::message("it's done"),
+        commit::allow_empty());
+    cmd.env("GIT_AUTHOR_NAME", "A U Thor");
+    cmd.env_remove("GIT_DIR");
+    assert_eq!(cmd.dry_run().unwrap(),
+        "env -u GIT_DIR GIT_AUTHOR_NAME='A U Thor' git commit '--message=fix: a b' '--message=it'\\''s done' --allow-empty");
+
+    let spec = cmd.dry_run_args();
+    assert_eq!(spec.argv, vec!["git", "commit", "--message=fix: a b", "--message=it's done", "--allow-empty"]);
+    assert_eq!(spec.current_dir.as_deref(), Some(Path::new("/tmp")));
+    assert!(!spec.env_clear);
+    assert_eq!(spec.env, vec![("GIT_AUTHOR_NAME".into(), Some("A U Thor".into())), ("GIT_DIR".into(), None)]);
+}
+
+#[test]
+fn test_error_classification() {
+    let path = gitwrap_test_path();
+    fs::create_dir(path.as_str()).unwrap();
+    init_repo_with_commit(&path);
+
+    {
+        let mut cmd = rev_parse!(Some(path.as_str()), rev_parse::git_dir());
+        cmd.env("GIT_DIR", "/nonexistent");
+        assert!(matches!(cmd.execute(), Err(WrapError::NotARepository(_))));
+    }
+
+    {
+        let cmd = rev_parse!(Some(path.as_str()), rev_parse::verify(), rev_parse::default("no-such-ref"));
+        assert!(matches!(cmd.execute(), Err(WrapError::UnknownRevision(_))));
+    }
+
+    {
+        let cmd = add!(Some(path.as_str()), add::pathspec("no-such-file"));
+        assert!(matches!(cmd.execute(), Err(WrapError::PathspecNotMatched(_))));
+    }
+
+    {
+        fs::write(format!("{path}/file.txt"), "content").unwrap();
+        fs::write(format!("{path}/.git/index.lock"), "").unwrap();
+        let cmd = add!(Some(path.as_str()), add::pathspec("file.txt"));
+        match cmd.execute() {
+            Err(e @ WrapError::IndexLocked(_)) => assert!(e.output().unwrap().stderr.contains("index.lock")),
+            _ => panic!("a held index.lock must be reported"),
+        }
+    }
+
+    let failed = |stdout: &str, stderr: &str| WrapOutput::new(Some(1), stdout.as_bytes().to_vec(), stderr.as_bytes().to_vec());
+    assert!(matches!(WrapError::classify(failed("CONFLICT (content): Merge conflict in a.txt\nAutomatic merge failed; fix conflicts and then commit the result.\n", "")),
+        WrapError::MergeConflict(_)));
+    assert!(matches!(WrapError::classify(failed("", " ! [rejected]        main -> main (non-fast-forward)\nerror: failed to push some refs\n")),
+        WrapError::NonFastForward(_)));
+    assert!(matches!(WrapError::classify(failed("", "fatal: Authentication failed for 'https://example.com/repo.git/'\n")),
+        WrapError::AuthenticationFailed(_)));
+    assert!(matches!(WrapError::classify(failed("", "error: Your local changes to the following files would be overwritten by checkout:\n\ta.txt\n")),
+        WrapError::DirtyWorktree(_)));
+    assert!(matches!(WrapError::classify(failed("", "error: something else\n")),
+        WrapError::ExitStatus(_)));
+    assert!(matches!(WrapError::classify(failed("+CONFLICT (content): Merge conflict in a.txt\n fatal: not a git repository\n", "")),
+        WrapError::ExitStatus(_)));
+
+    // a failing command whose stdout payload quotes failure messages is not misclassified
+    let dir = Path::new(path.as_str());
+    fs::write(dir.join("payload_old.txt"), "one\n").unwrap();
+    fs::write(dir.join("payload_new.txt"), "CONFLICT (content): Merge conflict in a.txt\nfatal: not a git repository\n").unwrap();
+    let cmd = diff!(Some(path.as_str()),
+        diff::no_index(),
+        diff::exit_code(),
+        diff::hyphen_hyphen(),
+        diff::pathspec("payload_old.txt"),
+        diff::pathspec("payload_new.txt"));
+    match cmd.execute_output() {
+        Err(WrapError::ExitStatus(o)) => assert!(o.stdout.contains("+CONFLICT (content)")),
+        other => panic!("a differing diff must fail with its exit status, got {:?}", other.err()),
+    }
+
+    fs::remove_dir_all(path.as_str()).unwrap();
+}
+
+#[test]
+fn test_execute_output() {
+    let path = gitwrap_test_path();
+    fs::create_dir(path.as_str()).unwrap();
+
+    {
+        let cmd = init!(Some(path.as_str()), init::quiet());
+        assert!(cmd.execute_output().is_ok());
+    }
+
+    {
+        let cmd = rev_parse!(Some(path.as_str()), rev_parse::git_dir());
+        let o = cmd.execute_output().unwrap();
+        assert_eq!(o.code, Some(0));
+        assert_eq!(o.stdout, ".git\n");
+        assert!(o.stderr.is_empty());
+        assert_eq!(o.raw_stdout, b".git\n");
+    }
+
+    {
+        let cmd = rev_parse!(Some(path.as_str()),
+            rev_parse::verify(),
+            rev_parse::quiet());
+        match cmd.execute_output() {
+            Err(WrapError::ExitStatus(o)) => {
+                assert!(o.code.is_some_and(|c| c != 0));
+                assert!(o.stdout.is_empty());
+            }
+            _ => panic!("rev-parse --verify without arguments must fail"),
+        }
+    }
+
+    fs::remove_dir_all(path.as_str()).unwrap();
+}
+
+#[test]
+fn test_env_and_stdin() {
+    let path = gitwrap_test_path();
+    fs::create_dir(path.as_str()).unwrap();
+
+    {
+        let cmd = init!(Some(path.as_str()), init::quiet());
+        assert!(cmd.execute().is_ok());
+    }
+
+    {
+        let cmd_set = String::from("env -u GIT_DIR GIT_AUTHOR_NAME=gitwrap GIT_AUTHOR_EMAIL=test@email.com GIT_COMMITTER_NAME=gitwrap GIT_COMMITTER_EMAIL=test@email.com git commit --allow-empty --file=-");
+        let mut cmd = commit!(Some(path.as_str()),
+            commit::allow_empty(),
+            commit::file("-"));
+        cmd.env_remove("GIT_DIR");
+        cmd.env("GIT_AUTHOR_NAME", "gitwrap");
+        cmd.env("GIT_AUTHOR_EMAIL", REPO_CONFIG_EMAIL);
+        cmd.env("GIT_COMMITTER_NAME", "gitwrap");
+        cmd.env("GIT_COMMITTER_EMAIL", REPO_CONFIG_EMAIL);
+        cmd.stdin(Stdin::from("message read from stdin"));
+        assert!(cmd.dry_run().unwrap().eq(&cmd_set));
+
+        assert!(cmd.execute().is_ok());
+    }
+
+    {
+        let cmd = rev_parse!(Some(path.as_str()),
+            rev_parse::verify(),
+            rev_parse::quiet(),
+            rev_parse::default("HEAD^{/read from stdin}"));
+        assert!(cmd.execute().is_ok());
+    }
+
+    fs::remove_dir_all(path.as_str()).unwrap();
+}
+
+#[test]
+fn test_execute_streaming() {
+    let path = gitwrap_test_path();
+    let origin = format!("{path}/origin");
+    let target = format!("{path}/target");
+    fs::create_dir_all(origin.as_str()).unwrap();
+    init_repo_with_commit(&origin);
+
+    let cmd = clone!(None,
+        clone::no_hardlinks(),
+        clone::progress(),
+        clone::repository(format!("file://{}", fs::canonicalize(&origin).unwrap().display()).as_str()),
+        clone::directory(target.as_str()));
+    let mut lines = Vec::new();
+    assert!(cmd.execute_streaming(|l| lines.push(l)).is_ok());
+
+    assert!(lines.iter().any(|l| matches!(l, StreamLine::Stderr(s) if s.starts_with("Cloning into"))));
+    assert!(lines.iter().any(|l| matches!(l, StreamLine::Progress(p) if p.phase == "Receiving objects" && p.done)));
+
+    fs::remove_dir_all(path.as_str()).unwrap();
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_spawn_bounded_and_reaped() {
+    let path = gitwrap_test_path();
+    fs::create_dir(path.as_str()).unwrap();
+    init_repo_with_commit(path.as_str());
+    let content: String = (0..200_000).map(|n| format!("line {}\n", n)).collect();
+    fs::write(Path::new(path.as_str()).join("big.txt"), content).unwrap();
+    let cmd = add!(Some(path.as_str()), add::pathspec("big.txt"));
+    assert!(cmd.execute().is_ok());
+
+    let state = |id: u32| fs::read_to_string(format!("/proc/{}/stat", id)).ok()
+        .and_then(|s| s.rsplit(") ").next().and_then(|r| r.chars().next()));
+    let mut cmd = git("show", Some(path.as_str()));
+    cmd.option(crate::optionarg::simple(":big.txt"));
+    let mut child = cmd.spawn().unwrap();
+    let id = child.id();
+    let first: Vec<StreamLine> = child.by_ref().take(10).collect();
+    assert_eq!(first[0], StreamLine::Stdout(String::from("line 0")));
+    thread::sleep(Duration::from_millis(200));
+    // output not consumed yet holds git back instead of piling up in memory
+    assert_eq!(state(id), Some('S'));
+    drop(child);
+    assert_eq!(state(id), None);
+
+    fs::remove_dir_all(path.as_str()).unwrap();
+}
+
+#[test]
+fn test_stream_stderr_lines() {
+    // stderr lines are passed on as soon as they end, whether with a newline or a carriage return
+    let mut cmd = WrapCommand::new("sh", None);
+    cmd.option(crate::optionarg::simple("-c"));
+    cmd.option(crate::optionarg::simple("echo warning >&2; sleep 2; printf 'Counting objects: 1\\r' >&2; sleep 5"));
+    let start = Instant::now();
+    let mut child = cmd.spawn().unwrap();
+    assert_eq!(child.next(), Some(StreamLine::Stderr(String::from("warning"))));
+    assert!(start.elapsed() < Duration::from_secs(1));
+    assert!(matches!(child.next(), Some(StreamLine::Progress(p)) if p.current == 1));
+    assert!(start.elapsed() < Duration::from_secs(4));
+    drop(child);
+
+    // output without any line break is not buffered whole
+    let mut cmd = WrapCommand::new("sh", None);
+    cmd.option(crate::optionarg::simple("-c"));
+    cmd.option(crate::optionarg::simple("head -c 200000 /dev/zero | tr '\\0' x >&2"));
+    let mut lengths = Vec::new();
+    cmd.execute_streaming(|line| if let StreamLine::Stderr(l) = line { lengths.push(l.len()) }).unwrap();
+    assert_eq!(lengths.iter().sum::<usize>(), 200000);
+    assert!(lengths.len() > 1 && lengths.iter().all(|l| *l <= 64 * 1024));
+}
+
+#[test]
+fn test_parse_progress() {
+    let p = parse_progress("Receiving objects:  45% (450/1000), 1.20 MiB | 2.00 MiB/s").unwrap();
+    assert!(!p.remote);
+    assert_eq!(p.phase, "Receiving objects");
+    assert_eq!(p.percent, Some(45));
+    assert_eq!(p.current, 450);
+    assert_eq!(p.total, Some(1000));
+    assert_eq!(p.throughput.as_deref(), Some("1.20 MiB | 2.00 MiB/s"));
+    assert!(!p.done);
+
+    let p = parse_progress("remote: Enumerating objects: 5, done.        ").unwrap();
+    assert!(p.remote);
+    assert_eq!(p.phase, "Enumerating objects");
+    assert_eq!(p.percent, None);
+    assert_eq!(p.current, 5);
+    assert!(p.done);
+
+    assert!(parse_progress("Cloning into 'gitwrap'...").is_none());
+    assert!(parse_progress("remote: Total 2 (delta 0), reused 0 (delta 0), pack-reused 0").is_none());
+}
+
+#[test]
+fn test_timeout() {
+    let path = gitwrap_test_path();
+    let origin = format!("{path}/origin");
+    let target = format!("{path}/target");
+    fs::create_dir_all(origin.as_str()).unwrap();
+    init_repo_with_commit(&origin);
+
+    let mut cmd = clone!(None,
+        clone::upload_pack("sleep 5; git-upload-pack"),
+        clone::repository(format!("file://{}", fs::canonicalize(&origin).unwrap().display()).as_str()),
+        clone::directory(target.as_str()));
+    cmd.timeout(Duration::from_millis(300));
+    cmd.cleanup_on_abort(target.as_str());
+
+    let start = Instant::now();
+    assert!(matches!(cmd.execute(), Err(WrapError::Timeout(_))));
+    assert!(start.elapsed() < Duration::from_secs(4));
+    assert!(!Path::new(&target).exists());
+
+    // an existing empty target is emptied, a repository holding data is left alone
+    fs::create_dir(target.as_str()).unwrap();
+    let mut cmd = clone!(None,
+        clone::upload_pack("sleep 5; git-upload-pack"),
+        clone::repository(format!("file://{}", fs::canonicalize(&origin).unwrap().display()).as_str()),
+        clone::directory(target.as_str()));
+    cmd.timeout(Duration::from_millis(300));
+    cmd.cleanup_on_abort(target.as_str());
+    cmd.cleanup_on_abort(origin.as_str());
+    assert!(matches!(cmd.execute(), Err(WrapError::Timeout(_))));
+    assert!(fs::read_dir(&target).unwrap().next().is_none());
+    assert!(Path::new(&origin).join(".git").exists());
+
+    fs::remove_dir_all(path.as_str()).unwrap();
+}
+
+#[test]
+fn test_timeout_streaming() {
+    // writes faster than output is polled, so the deadline has to be checked even while lines arrive
+    let mut cmd = WrapCommand::new("sh", None);
+    cmd.option(crate::optionarg::simple("-c"));
+    cmd.option(crate::optionarg::simple("exec yes line"));
+    cmd.env("GIT_CONFIG_VALUE_0", "Authorization: Bearer secret");
+    cmd.timeout(Duration::from_millis(300));
+
+    let start = Instant::now();
+    match cmd.execute_streaming(|_| {}) {
+        // the reported command leaves environment values out
+        Err(WrapError::Timeout(c)) => assert!(c.contains("GIT_CONFIG_VALUE_0") && !c.contains("secret")),
+        r => panic!("unexpected result {r:?}"),
+    }
+    assert!(start.elapsed() < Duration::from_secs(4));
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_stdin_not_inherited() {
+    // the host's stdin is never passed on, whether the command is bounded or not
+    let mut cmd = WrapCommand::new("sh", None);
+    cmd.option(crate::optionarg::simple("-c"));
+    cmd.option(crate::optionarg::simple("readlink /proc/self/fd/0"));
+    assert_eq!(cmd.execute_output().unwrap().stdout, "/dev/null\n");
+    cmd.timeout(Duration::from_secs(5));
+    assert_eq!(cmd.execute_output().unwrap().stdout, "/dev/null\n");
+    assert!(matches!(cmd.spawn().unwrap().next(), Some(StreamLine::Stdout(l)) if l == "/dev/null"));
+    #[cfg(feature = "async")]
+    {
+        let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+        assert_eq!(rt.block_on(cmd.execute_async()).unwrap(), "/dev/null\n");
+    }
+}
+
+#[test]
+fn test_cancel() {
+    let path = gitwrap_test_path();
+    fs::create_dir(path.as_str()).unwrap();
+    init_repo_with_commit(&path);
+
+    let mut cmd = commit!(Some(path.as_str()),
+        commit::allow_empty(),
+        commit::edit());
+    set_identity(&mut cmd);
+    cmd.env("GIT_EDITOR", "sleep 5;");
+    let handle = cmd.cancel_handle();
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(200));
+        handle.cancel();
+    });
+
+    let start = Instant::now();
+    assert!(matches!(cmd.execute_streaming(|_| {}), Err(WrapError::Cancelled(_))));
+    assert!(start.elapsed() < Duration::from_secs(4));
+
+    fs::remove_dir_all(path.as_str()).unwrap();
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn test_execute_async() {
+    let path = gitwrap_test_path();
+    fs::create_dir(path.as_str()).unwrap();
+    init_repo_with_commit(&path);
+    let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+
+    {
+        let cmd = rev_parse!(Some(path.as_str()), rev_parse::git_dir());
+        let o = rt.block_on(cmd.execute_output_async()).unwrap();
+        assert_eq!(o.stdout, ".git\n");
+    }
+
+    {
+        // the future is Send and may outlive the command: spawn it, moving the command in
+        let cmd = rev_parse!(Some(path.as_str()), rev_parse::show_toplevel());
+        let detached = cmd.execute_async();
+        let handle = rt.spawn(async move { cmd.execute_output_async().await });
+        let toplevel = rt.block_on(handle).unwrap().unwrap().stdout;
+        assert_eq!(rt.block_on(rt.spawn(detached)).unwrap().unwrap(), toplevel);
+    }
+
+    {
+        let mut cmd = commit!(Some(path.as_str()),
+            commit::allow_empty(),
+            commit::edit());
+        set_identity(&mut cmd);
+        cmd.env("GIT_EDITOR", "sleep 5;");
+        cmd.timeout(Duration::from_millis(200));
+        let start = Instant::now();
+        assert!(matches!(rt.block_on(cmd.execute_async()), Err(WrapError::Timeout(_))));
+        assert!(start.elapsed() < Duration::from_secs(4));
+    }
+
+    fs::remove_dir_all(path.as_str()).unwrap();
+}
+
+#[test]
+fn test_mock_executor() {
+    let mock = Arc::new(MockExecutor::new());
+    mock.expect("git status --porcelain=v2", 0, "1 .M N... 100644 100644 100644 abc abc src/lib.rs\n", "");
+    mock.expect("git rev-parse --verify --quiet", 128, "", "fatal: Needed a single revision\n");
+    set_thread_executor(Some(mock.clone()));
+
+    {
+        let cmd = status!(Some("/nonexistent"), status::porcelain("v2"));
+        let o = cmd.execute_output().unwrap();
+        assert!(o.stdout.ends_with("src/lib.rs\n"));
+    }
+
+    {
+        let cmd = rev_parse!(None, rev_parse::verify(), rev_parse::quiet());
+        match cmd.execute_output() {
+            Err(WrapError::UnknownRevision(o)) => assert_eq!(o.code, Some(128)),
+            _ => panic!("canned exit code must be reported"),
+        }
+    }
+
+    {
+        let cmd = status!(None, status::short());
+        assert!(matches!(cmd.execute(), Err(WrapError::FailedExecuteProcess(_))));
+    }
+
+    set_thread_executor(None);
+    assert_eq!(mock.executed(), vec!["git status --porcelain=v2", "git rev-parse --verify --quiet", "git status --short"]);
+    assert!(mock.pending().is_empty());
+}
+
+#[test]
+fn test_cassette_executor() {
+    let path = gitwrap_test_path();
+    let repo = format!("{path}/repo");
+    let cassette = format!("{path}/cassette.json");
+    fs::create_dir_all(repo.as_str()).unwrap();
+    init_repo_with_commit(&repo);
+
+    {
+        let recorder = Arc::new(CassetteExecutor::record(cassette.as_str()));
+        let mut cmd = rev_parse!(Some(repo.as_str()), rev_parse::git_dir());
+        cmd.executor(recorder.clone());
+        assert_eq!(cmd.execute().unwrap(), ".git\n");
+
+        let mut cmd = rev_parse!(Some(repo.as_str()), rev_parse::verify(), rev_parse::quiet());
+        cmd.executor(recorder.clone());
+        assert!(cmd.execute().is_err());
+        assert_eq!(recorder.remaining(), 2);
+    }
+
+    fs::remove_dir_all(repo.as_str()).unwrap();
+
+    {
+        let player = Arc::new(CassetteExecutor::replay(cassette.as_str()).unwrap());
+        let mut cmd = rev_parse!(Some(repo.as_str()), rev_parse::git_dir());
+        cmd.executor(player.clone());
+        assert_eq!(cmd.execute().unwrap(), ".git\n");
+
+        let mut cmd = rev_parse!(Some(repo.as_str()), rev_parse::show_toplevel());
+        cmd.executor(player.clone());
+        assert!(matches!(cmd.execute(), Err(WrapError::FailedExecuteProcess(_))));
+
+        let mut cmd = rev_parse!(Some(repo.as_str()), rev_parse::verify(), rev_parse::quiet());
+        cmd.executor(player.clone());
+        assert!(matches!(cmd.execute(), Err(WrapError::ExitStatus(_))));
+        assert_eq!(player.remaining(), 0);
+    }
+
+    fs::remove_dir_all(path.as_str()).unwrap();
+}
+
+#[test]
+fn test_git_version() {
+    assert_eq!(GitVersion::parse("git version 2.39.5\n"), Some(GitVersion::new(2, 39, 5)));
+    assert_eq!(GitVersion::parse("git version 2.45.1.windows.1"), Some(GitVersion::new(2, 45, 1)));
+    assert_eq!(GitVersion::parse("git version 2.47.0-rc1"), Some(GitVersion::new(2, 47, 0)));
+    assert_eq!(GitVersion::parse("git version 3.0"), Some(GitVersion::new(3, 0, 0)));
+    assert_eq!(GitVersion::parse("not git"), None);
+    assert!(GitVersion::new(2, 9, 0) < GitVersion::new(2, 24, 0));
+
+    assert!(crate::git_version().is_ok());
+
+    let mock = Arc::new(MockExecutor::new());
+    mock.expect("old-git --version", 0, "git version 2.20.1\n", "");
+    mock.expect("new-git --version", 0, "git version 2.39.5\n", "");
+    mock.expect("new-git rebase --keep-base", 0, "", "");
+
+    let mut cmd = rebase!(None, rebase::keep_base());
+    cmd.program("old-git");
+    cmd.executor(mock.clone());
+    match cmd.execute() {
+        Err(WrapError::UnsupportedVersion(option, required, found)) => {
+            assert_eq!(option, "--keep-base");
+            assert_eq!(required, GitVersion::new(2, 24, 0));
+            assert_eq!(found, GitVersion::new(2, 20, 1));
+        }
+        _ => panic!("--keep-base must be rejected on git 2.20"),
+    }
+
+    // entries only apply to their own subcommand, not to a path or a ref of the same name
+    mock.expect("old-git -C worktree log -z -- worktree", 0, "", "");
+    mock.expect("old-git push origin stash", 0, "", "");
+    let mut cmd = log!(global: [global::directory("worktree")], None, log::null(), log::hyphen_hyphen(), log::pathspec("worktree"));
+    cmd.program("old-git");
+    cmd.executor(mock.clone());
+    assert!(cmd.execute().is_ok());
+    let mut cmd = git("push", None);
+    cmd.option(crate::optionarg::simple("origin"));
+    cmd.option(crate::optionarg::simple("stash"));
+    cmd.program("old-git");
+    cmd.executor(mock.clone());
+    assert!(cmd.execute().is_ok());
+    let mut cmd = worktree!(None, worktree::list(), worktree::null());
+    cmd.program("old-git");
+    cmd.executor(mock.clone());
+    assert!(matches!(cmd.execute(), Err(WrapError::UnsupportedVersion(_, _, _))));
+
+    let mut cmd = rebase!(None, rebase::keep_base());
+    cmd.program("new-git");
+    cmd.executor(mock.clone());
+    assert!(cmd.execute().is_ok());
+    assert!(mock.pending().is_empty());
+
+    // another executor reports its own version of the same binary
+    let other = Arc::new(MockExecutor::new());
+    other.expect("old-git --version", 0, "git version 2.45.0\n", "");
+    other.expect("old-git rebase --keep-base", 0, "", "");
+    let mut cmd = rebase!(None, rebase::keep_base());
+    cmd.program("old-git");
+    cmd.executor(other.clone());
+    assert!(cmd.execute().is_ok());
+    assert!(other.pending().is_empty());
+}
+
+#[test]
+fn test_parse_status_porcelain_v2() {
+    let oid = "1111111111111111111111111111111111111111";
+    let zero = "0000000000000000000000000000000000000000";
+    let text = format!("# branch.oid {oid}\n\
+        # branch.head main\n\
+        # branch.upstream origin/main\n\
+        # branch.ab +2 -1\n\
+        # stash 3\n\
+        1 .M N... 100644 100644 100644 {oid} {oid} src/lib.rs\n\
+        2 R. N... 100644 100644 100644 {oid} {oid} R87 \"new name\\t.rs\"\told.rs\n\
+        u UU N... 100644 100644 100644 100644 {oid} {oid} {oid} conflict file.txt\n\
+        ? \"caf\\303\\251.txt\"\n\
+        ! target/\n");
+    let status = status::parse_porcelain_v2(text.as_str()).unwrap();
+    assert_eq!(status.branch.oid.as_deref(), Some(oid));
+    assert_eq!(status.branch.head.as_deref(), Some("main"));
+    assert_eq!(status.branch.upstream.as_deref(), Some("origin/main"));
+    assert_eq!((status.branch.ahead, status.branch.behind), (Some(2), Some(1)));
+    assert_eq!(status.stash, Some(3));
+    assert_eq!(status.entries.len(), 5);
+    match &status.entries[1] {
+        status::StatusEntry::RenamedOrCopied { code, kind, score, path, orig_path, .. } => {
+            assert_eq!((code.index, code.worktree), ('R', '.'));
+            assert_eq!((*kind, *score), ('R', 87));
+            assert_eq!(path, "new name\t.rs");
+            assert_eq!(orig_path, "old.rs");
+        }
+        e => panic!("unexpected entry {:?}", e),
+    }
+    match &status.entries[2] {
+        status::StatusEntry::Unmerged { mode_stage1, path, .. } => {
+            assert_eq!(*mode_stage1, 0o100644);
+            assert_eq!(path, "conflict file.txt");
+        }
+        e => panic!("unexpected entry {:?}", e),
+    }
+    assert_eq!(status.entries[3], status::StatusEntry::Untracked(String::from("café.txt")));
+    assert_eq!(status.entries[4], status::StatusEntry::Ignored(String::from("target/")));
+
+    let text = format!("# branch.oid (initial)\0# branch.head (detached)\0\
+        2 R. N... 100644 100644 100644 {oid} {oid} R100 new\tname\0old name\0\
+        1 A. N... 000000 100644 100644 {zero} {oid} with \"quote\"\0");
+    let status = status::parse_porcelain_v2(text.as_str()).unwrap();
+    assert_eq!(status.branch.oid, None);
+    assert_eq!(status.branch.head, None);
+    assert_eq!(status.entries[0].path(), "new\tname");
+    assert_eq!(status.entries[1].path(), "with \"quote\"");
+    assert!(matches!(status::parse_porcelain_v2("1 .M bogus"), Err(WrapError::ParseOutput(_))));
+
+    let path = gitwrap_test_path();
+    fs::create_dir(path.as_str()).unwrap();
+    init_repo_with_commit(path.as_str());
+    fs::write(Path::new(path.as_str()).join("new file.txt"), "content").unwrap();
+    let cmd = status!(Some(path.as_str()), status::porcelain("v2"), status::branch(), status::null());
+    let status = status::parse_porcelain_v2(cmd.execute().unwrap().as_str()).unwrap();
+    assert!(status.branch.oid.is_some());
+    assert_eq!(status.entries, vec![status::StatusEntry::Untracked(String::from("new file.txt"))]);
+
+    fs::remove_dir_all(path.as_str()).unwrap();
+}
+
+#[test]
+fn test_repository() {
+    let path = gitwrap_test_path();
+    fs::create_dir(path.as_str()).unwrap();
+    let cmd = init!(Some(path.as_str()), init::quiet());
+    assert!(cmd.execute().is_ok());
+    let subdir = format!("{}/sub", path);
+    fs::create_dir(subdir.as_str()).unwrap();
+
+    assert!(matches!(Repository::open(subdir.as_str()), Err(WrapError::NotARepository(_))));
+    let repo = Repository::discover(subdir.as_str()).unwrap();
+    assert_eq!(repo, Repository::open(path.as_str()).unwrap());
+    assert!(!repo.is_bare());
+    let top = fs::canonicalize(path.as_str()).unwrap();
+    assert_eq!(repo.work_tree(), top.to_str());
+    assert_eq!(Path::new(repo.git_dir()), top.join(".git"));
+
+    assert_eq!(repo.head().unwrap(), None);
+    assert!(repo.current_branch().unwrap().is_some());
+    assert!(!repo.is_dirty().unwrap());
+
+    fs::write(top.join("file.txt"), "content").unwrap();
+    assert!(!repo.is_dirty().unwrap());
+    let mut cmd = repo.add();
+    cmd.option(add::pathspec("file.txt"));
+    assert!(cmd.execute().is_ok());
+    assert!(repo.is_dirty().unwrap());
+
+    let mut cmd = repo.commit();
+    cmd.option(commit::message("first"));
+    set_identity(&mut cmd);
+    assert!(cmd.execute().is_ok());
+    assert!(!repo.is_dirty().unwrap());
+    let head = repo.head().unwrap().unwrap();
+    assert_eq!(head.len(), 40);
+
+    let mut cmd = repo.checkout();
+    cmd.option(checkout::detach());
+    assert!(cmd.execute().is_ok());
+    assert_eq!(repo.current_branch().unwrap(), None);
+    assert_eq!(repo.head().unwrap(), Some(head));
+
+    let bare = format!("{}/bare.git", path);
+    fs::create_dir(bare.as_str()).unwrap();
+    let cmd = init!(Some(bare.as_str()), init::quiet(), init::bare());
+    assert!(cmd.execute().is_ok());
+    let repo = Repository::open(bare.as_str()).unwrap();
+    assert!(repo.is_bare());
+    assert_eq!(repo.work_tree(), None);
+    assert_eq!(repo.path(), repo.git_dir());
+    assert!(!repo.is_dirty().unwrap());
+
+    fs::remove_dir_all(path.as_str()).unwrap();
+}
+
+#[test]
+fn test_log_commits() {
+    let path = gitwrap_test_path();
+    fs::create_dir(path.as_str()).unwrap();
+    init_repo_with_commit(path.as_str());
+
+    let mut cmd = commit!(Some(path.as_str()),
+        commit::allow_empty(),
+        commit::message("second commit\n\nA body\nspanning lines\n\nSigned-off-by: A U Thor <author@example.com>"));
+    set_identity(&mut cmd);
+    cmd.env("GIT_AUTHOR_NAME", "Another Author");
+    cmd.env("GIT_AUTHOR_DATE", "2005-04-07T22:13:13+02:00");
+    assert!(cmd.execute().is_ok());
+
+    let cmd = log!(Some(path.as_str()), log::commit_format());
+    let commits: Vec<log::Commit> = log::commits(&cmd).unwrap().map(|c| c.unwrap()).collect();
+    assert_eq!(commits.len(), 2);
+    let (second, first) = (&commits[0], &commits[1]);
+    assert_eq!(second.parents, vec![first.oid.clone()]);
+    assert!(first.parents.is_empty());
+    assert_eq!(second.subject, "second commit");
+    assert_eq!(second.body, "A body\nspanning lines\n\nSigned-off-by: A U Thor <author@example.com>");
+    assert_eq!(second.trailers, vec![log::Trailer {
+        key: String::from("Signed-off-by"),
+        value: String::from("A U Thor <author@example.com>"),
+    }]);
+    assert_eq!(second.author.name, "Another Author");
+    assert_eq!(second.author.time, 1112904793);
+    assert_eq!(second.author.date, "2005-04-07T22:13:13+02:00");
+    assert_eq!(second.committer.email, REPO_CONFIG_EMAIL);
+    assert_eq!(first.body, "");
+
+    let cmd = log!(Some(path.as_str()), log::commit_format(), log::max_count("5"), log::author("Another"));
+    let output = cmd.execute_output().unwrap();
+    assert_eq!(log::parse_commits(output.stdout.as_str()).unwrap(), vec![second.clone()]);
+
+    let cmd = log!(Some(path.as_str()), log::commit_format(), log::revision_range("no-such-branch"));
+    let mut commits = log::commits(&cmd).unwrap();
+    assert!(matches!(commits.next(), Some(Err(_))));
+    assert!(commits.next().is_none());
+
+    fs::remove_dir_all(path.as_str()).unwrap();
+}
+
+#[test]
+fn test_diff_parsers() {
+    let path = gitwrap_test_path();
+    fs::create_dir(path.as_str()).unwrap();
+    init_repo_with_commit(path.as_str());
+    let dir = Path::new(path.as_str());
+    let content: String = (1..=20).map(|n| format!("line {}\n", n)).collect();
+    fs::write(dir.join("moved.txt"), content.as_str()).unwrap();
+    fs::write(dir.join("edited file.txt"), "one\ntwo\nthree\n").unwrap();
+    fs::write(dir.join("gone.txt"), "bye\n").unwrap();
+    let cmd = add!(Some(path.as_str()), add::pathspec("."));
+    assert!(cmd.execute().is_ok());
+    let mut cmd = commit!(Some(path.as_str()), commit::message("files"));
+    set_identity(&mut cmd);
+    assert!(cmd.execute().is_ok());
+
+    fs::rename(dir.join("moved.txt"), dir.join("renamed.txt")).unwrap();
+    fs::write(dir.join("edited file.txt"), "one\n2\nthree\nfour\n").unwrap();
+    fs::remove_file(dir.join("gone.txt")).unwrap();
+    fs::write(dir.join("blob.bin"), [0u8, 1, 2, 0]).unwrap();
+    let cmd = add!(Some(path.as_str()), add::all());
+    assert!(cmd.execute().is_ok());
+
+    let cmd = diff!(Some(path.as_str()), diff::cached(), diff::raw(), diff::numstat(), diff::null(), diff::find_renames("50%"));
+    let diffs = diff::parse_raw(cmd.execute_output().unwrap().stdout.as_str()).unwrap();
+    let paths: Vec<(&str, diff::DiffStatus)> = diffs.iter().map(|d| (d.path(), d.status)).collect();
+    assert_eq!(paths, vec![
+        ("blob.bin", diff::DiffStatus::Added),
+        ("edited file.txt", diff::DiffStatus::Modified),
+        ("gone.txt", diff::DiffStatus::Deleted),
+        ("renamed.txt", diff::DiffStatus::Renamed),
+    ]);
+    assert!(diffs[0].binary && diffs[0].added.is_none());
+    assert_eq!(diffs[0].old_mode, None);
+    assert_eq!(diffs[0].new_mode, Some(0o100644));
+    assert_eq!((diffs[1].added, diffs[1].removed), (Some(2), Some(1)));
+    assert_eq!(diffs[2].new_path, None);
+    assert_eq!(diffs[3].old_path.as_deref(), Some("moved.txt"));
+    assert_eq!(diffs[3].score, Some(100));
+
+    let cmd = diff!(Some(path.as_str()), diff::cached(), diff::name_status(), diff::find_renames("50%"));
+    let diffs = diff::parse_name_status(cmd.execute_output().unwrap().stdout.as_str()).unwrap();
+    assert_eq!(diffs.len(), 4);
+    assert_eq!(diffs[1].path(), "edited file.txt");
+    assert_eq!(diffs[3].old_path.as_deref(), Some("moved.txt"));
+
+    let cmd = diff!(Some(path.as_str()), diff::cached(), diff::unified("1"), diff::find_renames("50%"));
+    let diffs = diff::parse_patch(cmd.execute_output().unwrap().stdout.as_str()).unwrap();
+    assert_eq!(diffs.len(), 4);
+    assert!(diffs[0].binary);
+    let edited = &diffs[1];
+    assert_eq!((edited.old_path.as_deref(), edited.new_path.as_deref()), (Some("edited file.txt"), Some("edited file.txt")));
+    assert_eq!((edited.added, edited.removed), (Some(2), Some(1)));
+    assert_eq!(edited.hunks.len(), 1);
+    assert_eq!((edited.hunks[0].old_start, edited.hunks[0].old_lines), (1, 3));
+    assert_eq!(edited.hunks[0].lines, vec![
+        diff::DiffLine::Context(String::from("one")),
+        diff::DiffLine::Removed(String::from("two")),
+        diff::DiffLine::Added(String::from("2")),
+        diff::DiffLine::Context(String::from("three")),
+        diff::DiffLine::Added(String::from("four")),
+    ]);
+    assert_eq!(diffs[2].status, diff::DiffStatus::Deleted);
+    assert_eq!(diffs[2].removed, Some(1));
+    assert_eq!(diffs[3].status, diff::DiffStatus::Renamed);
+    assert!(diffs[3].hunks.is_empty());
+
+    let diffs = diff::parse_numstat("1\t0\tsrc/{old => new}/lib.rs\n-\t-\t\"tab\\there\"\n").unwrap();
+    assert_eq!(diffs[0].old_path.as_deref(), Some("src/old/lib.rs"));
+    assert_eq!(diffs[0].new_path.as_deref(), Some("src/new/lib.rs"));
+    assert_eq!(diffs[0].status, diff::DiffStatus::Renamed);
+    assert_eq!(diffs[1].path(), "tab\there");
+    assert!(diffs[1].binary);
+
+    let patch = "diff --git \"a/caf\\303\\251\" \"b/caf\\303\\251\"\nold mode 100644\nnew mode 100755\n";
+    let diffs = diff::parse_patch(patch).unwrap();
+    assert_eq!(diffs[0].path(), "café");
+    assert_eq!((diffs[0].old_mode, diffs[0].new_mode), (Some(0o100644), Some(0o100755)));
+
+    let patch = "diff --git a/x b/y.txt b/x b/y.txt\nold mode 100644\nnew mode 100755\n\
+        diff --git a/x b/y.txt b/z b/w.txt\nindex 1111111..2222222 100644\n\
+        --- a/x b/y.txt\t\n+++ b/z b/w.txt\t\n@@ -1 +1 @@\n-1\n+2\n";
+    let diffs = diff::parse_patch(patch).unwrap();
+    assert_eq!(diffs[0].old_path.as_deref(), Some("x b/y.txt"));
+    assert_eq!(diffs[0].new_path.as_deref(), Some("x b/y.txt"));
+    assert_eq!(diffs[1].old_path.as_deref(), Some("x b/y.txt"));
+    assert_eq!(diffs[1].new_path.as_deref(), Some("z b/w.txt"));
+    assert_eq!((diffs[1].added, diffs[1].removed), (Some(1), Some(1)));
+
+    // mnemonic prefixes, carriage returns kept in the lines of a CRLF file
+    let patch = "diff --git i/x y w/x y\n--- i/x y\t\n+++ w/x y\t\n@@ -1 +1 @@\n-1\r\n+2\r\n";
+    let diffs = diff::parse_patch(patch).unwrap();
+    assert_eq!((diffs[0].old_path.as_deref(), diffs[0].new_path.as_deref()), (Some("x y"), Some("x y")));
+    assert_eq!(diffs[0].hunks[0].lines, vec![
+        diff::DiffLine::Removed(String::from("1\r")),
+        diff::DiffLine::Added(String::from("2\r")),
+    ]);
+
+    // no prefix: a path looking like a prefixed one is kept whole
+    let patch = "diff --git a/x a/x\nnew file mode 100644\n--- /dev/null\n+++ a/x\n@@ -0,0 +1 @@\n+1\n";
+    let diffs = diff::parse_patch(patch).unwrap();
+    assert_eq!((diffs[0].old_path.as_deref(), diffs[0].new_path.as_deref()), (None, Some("a/x")));
+
+    // the default prefixes override the user configuration
+    let mut cmd = diff!(Some(path.as_str()), diff::cached(), diff::default_prefixes(), diff::pathspec("edited file.txt"));
+    cmd.env("GIT_CONFIG_COUNT", "1");
+    cmd.env("GIT_CONFIG_KEY_0", "diff.mnemonicPrefix");
+    cmd.env("GIT_CONFIG_VALUE_0", "true");
+    let output = cmd.execute_output().unwrap().stdout;
+    assert!(output.starts_with("diff --git a/edited file.txt b/edited file.txt"));
+    assert_eq!(diff::parse_patch(output.as_str()).unwrap()[0].path(), "edited file.txt");
+
+    fs::remove_dir_all(path.as_str()).unwrap();
+}
+
+#[test]
+fn test_stash() {
+    let path = gitwrap_test_path();
+    fs::create_dir(path.as_str()).unwrap();
+    init_repo_with_commit(path.as_str());
+    let repo = Repository::open(path.as_str()).unwrap();
+    let dir = Path::new(path.as_str());
+    let branch = repo.current_branch().unwrap().unwrap();
+
+    fs::write(dir.join("tracked.txt"), "v1\n").unwrap();
+    let cmd = add!(Some(path.as_str()), add::pathspec("tracked.txt"));
+    assert!(cmd.execute().is_ok());
+    let mut cmd = stash!(Some(path.as_str()), stash::push(), stash::message("first: save"));
+    set_identity(&mut cmd);
+    assert!(cmd.execute().is_ok());
+    assert!(!dir.join("tracked.txt").exists());
+
+    fs::write(dir.join("untracked.txt"), "new\n").unwrap();
+    let mut cmd = stash!(Some(path.as_str()),
+        stash::push(),
+        stash::include_untracked(),
+        stash::hyphen_hyphen(),
+        stash::pathspec("untracked.txt"));
+    set_identity(&mut cmd);
+    assert!(cmd.dry_run().unwrap().ends_with("git stash push --include-untracked -- untracked.txt"));
+    assert!(cmd.execute().is_ok());
+    assert!(!dir.join("untracked.txt").exists());
+
+    let cmd = stash!(Some(path.as_str()), stash::list());
+    let entries = stash::parse_stash_list(cmd.execute_output().unwrap().stdout.as_str()).unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].index, 0);
+    assert_eq!(entries[0].reference, "stash@{0}");
+    assert_eq!(entries[0].branch.as_deref(), Some(branch.as_str()));
+    assert!(entries[0].message.ends_with("initial commit"));
+    assert_eq!(entries[1].message, "first: save");
+
+    let cmd = stash!(Some(path.as_str()), stash::show(), stash::patch(), stash::stash_ref("stash@{1}"));
+    assert!(cmd.execute().unwrap().contains("+v1"));
+
+    let cmd = stash!(Some(path.as_str()), stash::pop());
+    assert!(cmd.execute().is_ok());
+    assert!(dir.join("untracked.txt").exists());
+
+    let cmd = stash!(Some(path.as_str()), stash::apply(), stash::index());
+    assert!(cmd.execute().is_ok());
+    assert!(repo.is_dirty().unwrap());
+    let cmd = stash!(Some(path.as_str()), stash::drop());
+    assert!(cmd.execute().is_ok());
+    let cmd = stash!(Some(path.as_str()), stash::list());
+    assert!(cmd.execute_output().unwrap().stdout.is_empty());
+
+    let entries = stash::parse_stash_list("stash@{0}: WIP on (no branch): 1234abc subject\0stash@{1}: autostash\0").unwrap();
+    assert_eq!(entries[0].branch, None);
+    assert_eq!(entries[0].message, "1234abc subject");
+    assert_eq!((entries[1].index, entries[1].message.as_str()), (1, "autostash"));
+    assert!(matches!(stash::parse_stash_list("bogus: entry"), Err(WrapError::ParseOutput(_))));
+
+    fs::remove_dir_all(path.as_str()).unwrap();
+}
+
+#[test]
+fn test_worktree() {
+    let path = gitwrap_test_path();
+    fs::create_dir(path.as_str()).unwrap();
+    let main = format!("{}/main", path);
+    fs::create_dir(main.as_str()).unwrap();
+    init_repo_with_commit(main.as_str());
+    let repo = Repository::open(main.as_str()).unwrap();
+
+    let cmd = worktree!(Some(main.as_str()),
+        worktree::add(),
+        worktree::new_branch("feature"),
+        worktree::path("../feature"));
+    assert!(cmd.execute().is_ok());
+    let cmd = worktree!(Some(main.as_str()),
+        worktree::add(),
+        worktree::detach(),
+        worktree::lock(),
+        worktree::reason("ci job"),
+        worktree::path("../detached"),
+        worktree::commit_ish("HEAD"));
+    assert!(cmd.execute().is_ok());
+
+    let cmd = worktree!(Some(main.as_str()), worktree::list(), worktree::porcelain());
+    let worktrees = worktree::parse_worktree_list(cmd.execute_output().unwrap().stdout.as_str()).unwrap();
+    assert_eq!(worktrees.len(), 3);
+    assert_eq!(Some(worktrees[0].path.as_str()), repo.work_tree());
+    assert_eq!(worktrees[0].head, repo.head().unwrap());
+    let feature = worktrees.iter().find(|w| w.path.ends_with("/feature")).unwrap();
+    assert_eq!(feature.branch.as_deref(), Some("refs/heads/feature"));
+    assert!(!feature.detached && feature.locked.is_none());
+    let detached = worktrees.iter().find(|w| w.path.ends_with("/detached")).unwrap();
+    assert!(detached.detached && detached.branch.is_none());
+    assert_eq!(detached.locked.as_deref(), Some("ci job"));
+
+    let cmd = worktree!(Some(main.as_str()), worktree::move_worktree(), worktree::path("../feature"), worktree::new_path("../moved"));
+    assert!(cmd.execute().is_ok());
+    let cmd = worktree!(Some(main.as_str()), worktree::unlock(), worktree::path("../detached"));
+    assert!(cmd.execute().is_ok());
+    let cmd = worktree!(Some(main.as_str()), worktree::remove(), worktree::path("../detached"));
+    assert!(cmd.execute().is_ok());
+    fs::remove_dir_all(format!("{}/moved", path)).unwrap();
+    let cmd = worktree!(Some(main.as_str()), worktree::list(), worktree::porcelain());
+    let worktrees = worktree::parse_worktree_list(cmd.execute_output().unwrap().stdout.as_str()).unwrap();
+    assert_eq!(worktrees.len(), 2);
+    assert!(worktrees[1].path.ends_with("moved"));
+    assert!(worktrees[1].prunable.is_some());
+    let cmd = worktree!(Some(main.as_str()), worktree::prune());
+    assert!(cmd.execute().is_ok());
+
+    let listing = "worktree /srv/repo.git\0bare\0\0worktree /srv/wt\0HEAD 1234\0detached\0locked multi\nline\0\0";
+    let worktrees = worktree::parse_worktree_list(listing).unwrap();
+    assert!(worktrees[0].bare && worktrees[0].head.is_none());
+    assert_eq!(worktrees[1].locked.as_deref(), Some("multi\nline"));
+    let worktrees = worktree::parse_worktree_list("worktree /srv/wt\nlocked\nprunable \"gone\\tdir\"\n").unwrap();
+    assert_eq!(worktrees[0].locked.as_deref(), Some(""));
+    assert_eq!(worktrees[0].prunable.as_deref(), Some("gone\tdir"));
+
+    fs::remove_dir_all(path.as_str()).unwrap();
+}
+
+#[test]
+fn test_submodule() {
+    let path = gitwrap_test_path();
+    fs::create_dir(path.as_str()).unwrap();
+    let (dep, main) = (format!("{}/dep", path), format!("{}/main", path));
+    fs::create_dir(dep.as_str()).unwrap();
+    fs::create_dir(main.as_str()).unwrap();
+    init_repo_with_commit(dep.as_str());
+    init_repo_with_commit(main.as_str());
+    let dep_url = fs::canonicalize(dep.as_str()).unwrap().to_string_lossy().into_owned();
+    let allow_file = || global::config("protocol.file.allow", "always");
+
+    let cmd = submodule!(global: [allow_file()], Some(main.as_str()),
+        submodule::add(),
+        submodule::quiet(),
+        submodule::repository(dep_url.as_str()),
+        submodule::path("lib dep"));
+    assert!(cmd.execute().is_ok());
+    let mut cmd = commit!(Some(main.as_str()), commit::message("add submodule"));
+    set_identity(&mut cmd);
+    assert!(cmd.execute().is_ok());
+
+    let cmd = submodule!(Some(main.as_str()), submodule::status());
+    let status = submodule::parse_submodule_status(cmd.execute_output().unwrap().stdout.as_str()).unwrap();
+    assert_eq!(status.len(), 1);
+    assert_eq!(status[0].state, submodule::SubmoduleState::UpToDate);
+    assert_eq!(status[0].path, "lib dep");
+    assert_eq!(status[0].sha.len(), 40);
+    assert!(status[0].describe.is_some());
+
+    let mut cmd = commit!(Some(format!("{}/lib dep", main).as_str()), commit::allow_empty(), commit::message("moved on"));
+    set_identity(&mut cmd);
+    assert!(cmd.execute().is_ok());
+    let cmd = submodule!(Some(main.as_str()), submodule::status(), submodule::recursive());
+    let status = submodule::parse_submodule_status(cmd.execute_output().unwrap().stdout.as_str()).unwrap();
+    assert_eq!(status[0].state, submodule::SubmoduleState::Modified);
+
+    let cmd = submodule!(Some(main.as_str()), submodule::set_branch(), submodule::branch("main"), submodule::hyphen_hyphen(), submodule::path("lib dep"));
+    assert!(cmd.execute().is_ok());
+    let cmd = submodule!(Some(main.as_str()), submodule::set_url(), submodule::hyphen_hyphen(), submodule::path("lib dep"), submodule::new_url(dep_url.as_str()));
+    assert!(cmd.execute().is_ok());
+    let cmd = submodule!(Some(main.as_str()), submodule::sync(), submodule::recursive());
+    assert!(cmd.execute().is_ok());
+    let cmd = submodule!(Some(main.as_str()), submodule::foreach(), submodule::quiet(), submodule::command("echo $sm_path"));
+    assert_eq!(cmd.execute_output().unwrap().stdout, "lib dep\n");
+    let cmd = submodule!(Some(main.as_str()), submodule::deinit(), submodule::force(), submodule::all());
+    assert!(cmd.execute().is_ok());
+    let cmd = submodule!(Some(main.as_str()), submodule::status());
+    let status = submodule::parse_submodule_status(cmd.execute_output().unwrap().stdout.as_str()).unwrap();
+    assert_eq!(status[0].state, submodule::SubmoduleState::Uninitialized);
+    assert_eq!(status[0].describe, None);
+
+    let cmd = submodule!(global: [allow_file()], Some(main.as_str()),
+        submodule::update(),
+        submodule::init(),
+        submodule::recursive(),
+        submodule::jobs("2"));
+    assert!(cmd.execute().is_ok());
+    let cmd = submodule!(Some(main.as_str()), submodule::status());
+    let status = submodule::parse_submodule_status(cmd.execute_output().unwrap().stdout.as_str()).unwrap();
+    assert_eq!(status[0].state, submodule::SubmoduleState::UpToDate);
+
+    let status = submodule::parse_submodule_status("U0000000000000000000000000000000000000000 conflicted\n").unwrap();
+    assert_eq!(status[0].state, submodule::SubmoduleState::MergeConflict);
+    assert!(matches!(submodule::parse_submodule_status("*bogus"), Err(WrapError::ParseOutput(_))));
+
+    fs::remove_dir_all(path.as_str()).unwrap();
+}
+
+#[test]
+fn test_remote() {
+    let path = gitwrap_test_path();
+    fs::create_dir(path.as_str()).unwrap();
+    let (upstream, local) = (format!("{}/upstream", path), format!("{}/local", path));
+    fs::create_dir(upstream.as_str()).unwrap();
+    fs::create_dir(local.as_str()).unwrap();
+    init_repo_with_commit(upstream.as_str());
+    init_repo_with_commit(local.as_str());
+    let upstream_url = fs::canonicalize(upstream.as_str()).unwrap().to_string_lossy().into_owned();
+
+    let cmd = remote!(Some(local.as_str()), remote::add(), remote::fetch(), remote::name("upstream"), remote::url(upstream_url.as_str()));
+    assert!(cmd.execute().is_ok());
+    let cmd = remote!(Some(local.as_str()), remote::add(), remote::mirror("push"), remote::name("backup"), remote::url("/srv/backup.git"));
+    assert!(cmd.execute().is_ok());
+    let cmd = remote!(Some(local.as_str()), remote::set_url(), remote::add_url(), remote::push(), remote::name("backup"), remote::url("/srv/other.git"));
+    assert!(cmd.execute().is_ok());
+    let cmd = remote!(Some(local.as_str()), remote::set_url(), remote::add_url(), remote::push(), remote::name("backup"), remote::url("/srv/third.git"));
+    assert!(cmd.execute().is_ok());
+    let cmd = remote!(Some(local.as_str()), remote::set_url(), remote::delete(), remote::push(), remote::name("backup"), remote::url("third"));
+    assert!(cmd.execute().is_ok());
+
+    let cmd = remote!(Some(local.as_str()), remote::verbose());
+    let remotes = remote::parse_remotes(cmd.execute_output().unwrap().stdout.as_str()).unwrap();
+    assert_eq!(remotes, vec![
+        remote::Remote {
+            name: String::from("backup"),
+            fetch_url: Some(String::from("/srv/backup.git")),
+            push_urls: vec![String::from("/srv/other.git")],
+        },
+        remote::Remote {
+            name: String::from("upstream"),
+            fetch_url: Some(upstream_url.clone()),
+            push_urls: vec![upstream_url.clone()],
+        },
+    ]);
+
+    let cmd = remote!(Some(local.as_str()), remote::get_url(), remote::name("upstream"));
+    assert_eq!(cmd.execute_output().unwrap().stdout.trim_end(), upstream_url);
+    let cmd = remote!(Some(local.as_str()), remote::set_head(), remote::auto(), remote::name("upstream"));
+    assert!(cmd.execute().is_ok());
+    let cmd = remote!(Some(local.as_str()), remote::rename(), remote::name("upstream"), remote::name("origin"));
+    assert!(cmd.execute().is_ok());
+    let cmd = remote!(Some(local.as_str()), remote::prune(), remote::dry_run(), remote::name("origin"));
+    assert!(cmd.execute().is_ok());
+    let cmd = remote!(Some(local.as_str()), remote::show(), remote::no_query(), remote::name("origin"));
+    assert!(cmd.execute().is_ok());
+    let cmd = remote!(Some(local.as_str()), remote::remove(), remote::name("backup"));
+    assert!(cmd.execute().is_ok());
+    let cmd = remote::remote(Some(local.as_str()));
+    let remotes = remote::parse_remotes(cmd.execute_output().unwrap().stdout.as_str()).unwrap();
+    assert_eq!(remotes.iter().map(|r| r.name.as_str()).collect::<Vec<_>>(), vec!["origin"]);
+    assert!(remotes[0].fetch_url.is_none());
+
+    let remotes = remote::parse_remotes("origin\thttps://example.com/r.git (fetch) [blob:none]\norigin\thttps://example.com/r.git (push)\n").unwrap();
+    assert_eq!(remotes[0].fetch_url.as_deref(), Some("https://example.com/r.git"));
+    assert!(matches!(remote::parse_remotes("origin\turl (bogus)"), Err(WrapError::ParseOutput(_))));
+
+    fs::remove_dir_all(path.as_str()).unwrap();
+}
+
+#[test]
+fn test_cherry_pick_and_revert() {
+    let path = gitwrap_test_path();
+    fs::create_dir(path.as_str()).unwrap();
+    init_repo_with_commit(path.as_str());
+    let repo = Repository::open(path.as_str()).unwrap();
+    let dir = Path::new(path.as_str());
+    let commit_file = |name: &str, content: &str, message: &str| {
+        fs::write(dir.join(name), content).unwrap();
+        let cmd = add!(Some(path.as_str()), add::pathspec(name));
+        assert!(cmd.execute().is_ok());
+        let mut cmd = commit!(Some(path.as_str()), commit::message(message));
+        set_identity(&mut cmd);
+        assert!(cmd.execute().is_ok());
+    };
+    commit_file("f", "base\n", "base");
+    let main = repo.current_branch().unwrap().unwrap();
+    let cmd = checkout!(Some(path.as_str()), checkout::new_branch("feature"));
+    assert!(cmd.execute().is_ok());
+    commit_file("f", "feature\n", "c1");
+    commit_file("g", "g\n", "c2");
+    let mut cmd = repo.rev_parse();
+    cmd.option(rev_parse::verify());
+    cmd.option(crate::optionarg::simple("HEAD~1"));
+    let c1 = String::from(cmd.execute_output().unwrap().stdout.trim());
+    let cmd = checkout!(Some(path.as_str()), checkout::branch(main.as_str()));
+    assert!(cmd.execute().is_ok());
+    commit_file("f", "main\n", "conflicting");
+    assert_eq!(repo.sequencer_state().unwrap(), None);
+
+    let range = format!("{}..feature", main);
+    let mut cmd = cherry_pick!(Some(path.as_str()), cherry_pick::record_origin(), cherry_pick::commit(range.as_str()));
+    set_identity(&mut cmd);
+    assert!(matches!(cmd.execute(), Err(WrapError::MergeConflict(_))));
+    let state = repo.sequencer_state().unwrap().unwrap();
+    assert_eq!(state.operation, SequencerOperation::CherryPick);
+    assert_eq!(state.current.as_deref(), Some(c1.as_str()));
+    assert_eq!(state.todo.iter().map(|s| s.subject.as_str()).collect::<Vec<_>>(), vec!["c1", "c2"]);
+    assert_eq!(state.todo[0].action, "pick");
+    assert!(c1.starts_with(state.todo[0].oid.as_str()));
+
+    fs::write(dir.join("f"), "resolved\n").unwrap();
+    let cmd = add!(Some(path.as_str()), add::pathspec("f"));
+    assert!(cmd.execute().is_ok());
+    let mut cmd = cherry_pick!(Some(path.as_str()), cherry_pick::continue_cherry_pick());
+    set_identity(&mut cmd);
+    cmd.env("GIT_EDITOR", "true");
+    assert!(cmd.execute().is_ok());
+    assert_eq!(repo.sequencer_state().unwrap(), None);
+    assert!(dir.join("g").exists());
+
+    let mut cmd = revert!(Some(path.as_str()), revert::no_edit(), revert::commit("HEAD~2"));
+    set_identity(&mut cmd);
+    assert!(cmd.execute().is_err());
+    let state = repo.sequencer_state().unwrap().unwrap();
+    assert_eq!(state.operation, SequencerOperation::Revert);
+    assert!(state.current.is_some());
+    let cmd = revert!(Some(path.as_str()), revert::abort());
+    assert!(cmd.execute().is_ok());
+    assert_eq!(repo.sequencer_state().unwrap(), None);
+
+    let cmd = cherry_pick!(None, cherry_pick::mainline("1"), cherry_pick::no_commit(), cherry_pick::strategy("ort"), cherry_pick::commit("abc"));
+    assert_eq!(cmd.dry_run().unwrap(), "git cherry-pick --mainline 1 --no-commit --strategy=ort abc");
+
+    fs::remove_dir_all(path.as_str()).unwrap();
+}
+
+#[test]
+fn test_bisect() {
+    let path = gitwrap_test_path();
+    fs::create_dir(path.as_str()).unwrap();
+    init_repo_with_commit(path.as_str());
+    let repo = Repository::open(path.as_str()).unwrap();
+    let dir = Path::new(path.as_str());
+    let mut oids = Vec::new();
+    for n in 1..=8 {
+        fs::write(dir.join("value"), n.to_string()).unwrap();
+        let cmd = add!(Some(path.as_str()), add::pathspec("value"));
+        assert!(cmd.execute().is_ok());
+        let mut cmd = commit!(Some(path.as_str()), commit::message(format!("value {}", n).as_str()));
+        set_identity(&mut cmd);
+        assert!(cmd.execute().is_ok());
+        oids.push(repo.head().unwrap().unwrap());
+    }
+    let branch = repo.current_branch().unwrap();
+
+    let mut tested = Vec::new();
+    let outcome = bisect::find_first_bad(Some(path.as_str()), "HEAD", &["HEAD~7"], |oid| {
+        tested.push(String::from(oid));
+        let value: u32 = fs::read_to_string(dir.join("value")).unwrap().parse().unwrap();
+        match value {
+            3 => bisect::BisectVerdict::Skip,
+            v if v >= 5 => bisect::BisectVerdict::Bad,
+            _ => bisect::BisectVerdict::Good,
+        }
+    }).unwrap();
+    assert_eq!(outcome.first_bad, oids[4]);
+    assert!(outcome.log.contains(format!("# first bad commit: [{}]", oids[4]).as_str()));
+    assert!(!tested.is_empty() && tested.len() < 7);
+    assert_eq!(repo.current_branch().unwrap(), branch);
+
+    let result = bisect::find_first_bad(Some(path.as_str()), "HEAD", &["no-such-ref"], |_| bisect::BisectVerdict::Good);
+    assert!(matches!(result, Err(WrapError::UnknownRevision(_))));
+
+    let mut calls = 0;
+    let result = bisect::find_first_bad(Some(path.as_str()), "HEAD", &[], |_| {
+        calls += 1;
+        bisect::BisectVerdict::Bad
+    });
+    assert!(matches!(result, Err(WrapError::InvalidArgument(_))));
+    assert_eq!(calls, 0);
+
+    let cmd = bisect!(Some(path.as_str()), bisect::terms());
+    assert!(cmd.execute().is_err());
+
+    fs::remove_dir_all(path.as_str()).unwrap();
+}
+
+#[test]
+fn test_blame() {
+    let path = gitwrap_test_path();
+    fs::create_dir(path.as_str()).unwrap();
+    init_repo_with_commit(path.as_str());
+    let repo = Repository::open(path.as_str()).unwrap();
+    let dir = Path::new(path.as_str());
+    let mut oids = Vec::new();
+    for (content, message) in [("a\nb\nc\n", "first"), ("a\nB\nc\nd\n", "second")] {
+        fs::write(dir.join("file.txt"), content).unwrap();
+        let cmd = add!(Some(path.as_str()), add::pathspec("file.txt"));
+        assert!(cmd.execute().is_ok());
+        let mut cmd = commit!(Some(path.as_str()), commit::message(message));
+        set_identity(&mut cmd);
+        assert!(cmd.execute().is_ok());
+        oids.push(repo.head().unwrap().unwrap());
+    }
+
+    let cmd = blame!(Some(path.as_str()), blame::porcelain(), blame::file("file.txt"));
+    let result = blame::parse_blame_porcelain(cmd.execute_output().unwrap().stdout.as_str()).unwrap();
+    assert_eq!(result.lines.len(), 4);
+    assert_eq!(result.commits.len(), 2);
+    let expected = [(&oids[0], "a"), (&oids[1], "B"), (&oids[0], "c"), (&oids[1], "d")];
+    for (n, (line, (oid, content))) in result.lines.iter().zip(expected).enumerate() {
+        assert_eq!(&line.oid, oid);
+        assert_eq!(line.final_line, n as u32 + 1);
+        assert_eq!(line.original_line, n as u32 + 1);
+        assert_eq!(line.filename, "file.txt");
+        assert_eq!(line.content, content);
+    }
+    let commit = result.commit(&result.lines[1]).unwrap();
+    assert_eq!(commit.summary, "second");
+    assert_eq!(commit.author, "gitwrap");
+    assert!(commit.author_time > 0);
+    assert_eq!(commit.previous, Some((oids[0].clone(), String::from("file.txt"))));
+
+    let cmd = blame!(Some(path.as_str()),
+        blame::line_porcelain(),
+        blame::line_range("2,3"),
+        blame::ignore_whitespace(),
+        blame::detect_moves_threshold(10),
+        blame::ignore_rev(oids[1].as_str()),
+        blame::rev("HEAD"),
+        blame::hyphen_hyphen(),
+        blame::file("file.txt"));
+    assert!(cmd.dry_run().unwrap().ends_with(format!("git blame --line-porcelain -L 2,3 -w -M10 --ignore-rev {} HEAD -- file.txt", oids[1]).as_str()));
+    let result = blame::parse_blame_porcelain(cmd.execute_output().unwrap().stdout.as_str()).unwrap();
+    assert_eq!(result.lines.len(), 2);
+    assert!(result.lines.iter().all(|l| l.oid == oids[0]));
+    assert_eq!(result.commits.len(), 1);
+    assert_eq!(result.commit(&result.lines[0]).unwrap().summary, "first");
+
+    fs::remove_dir_all(path.as_str()).unwrap();
+}
+
+#[test]
+fn test_for_each_ref() {
+    let path = gitwrap_test_path();
+    fs::create_dir(path.as_str()).unwrap();
+    init_repo_with_commit(path.as_str());
+    let repo = Repository::open(path.as_str()).unwrap();
+    let main = repo.current_branch().unwrap().unwrap();
+    let cmd = checkout!(Some(path.as_str()),
+        checkout::track(),
+        checkout::new_branch("feature"),
+        checkout::branch(main.as_str()));
+    assert!(cmd.execute().is_ok());
+    for n in 1..=2 {
+        let mut cmd = commit!(Some(path.as_str()),
+            commit::allow_empty(),
+            commit::message(format!("feature\twork {}", n).as_str()));
+        set_identity(&mut cmd);
+        assert!(cmd.execute().is_ok());
+    }
+
+    let fmt = for_each_ref::RefFormat::new(&[
+        for_each_ref::RefAtom::ShortName,
+        for_each_ref::RefAtom::ObjectName,
+        for_each_ref::RefAtom::Head,
+        for_each_ref::RefAtom::Upstream,
+        for_each_ref::RefAtom::UpstreamTrack,
+        for_each_ref::RefAtom::AuthorEmail,
+        for_each_ref::RefAtom::CommitterDate,
+        for_each_ref::RefAtom::Subject,
+    ]);
+    let cmd = for_each_ref!(Some(path.as_str()),
+        fmt.option(),
+        for_each_ref::sort("refname"),
+        for_each_ref::pattern("refs/heads"));
+    let refs = fmt.parse(cmd.execute_output().unwrap().stdout.as_str()).unwrap();
+    assert_eq!(refs.len(), 2);
+    let feature = refs.iter().find(|r| r.refname == "refs/heads/feature").unwrap();
+    assert_eq!(feature.short_name.as_deref(), Some("feature"));
+    assert_eq!(feature.object_name, repo.head().unwrap());
+    assert!(feature.head);
+    assert_eq!(feature.upstream, Some(format!("refs/heads/{}", main)));
+    assert_eq!(feature.track, Some(for_each_ref::Tracking { ahead: 2, behind: 0, gone: false }));
+    assert_eq!(feature.author_email.as_deref(), Some(REPO_CONFIG_EMAIL));
+    assert!(feature.committer_date.unwrap() > 0);
+    assert_eq!(feature.subject.as_deref(), Some("feature\twork 2"));
+    let main_ref = refs.iter().find(|r| r.short_name.as_deref() == Some(main.as_str())).unwrap();
+    assert!(!main_ref.head);
+    assert_eq!(main_ref.upstream, None);
+    assert_eq!(main_ref.track, None);
+
+    let fmt = for_each_ref::RefFormat::new(&[]);
+    let cmd = for_each_ref!(Some(path.as_str()),
+        fmt.option(),
+        for_each_ref::count("1"),
+        for_each_ref::contains("HEAD"));
+    assert_eq!(cmd.dry_run().unwrap(), "git for-each-ref '--format=%1e%(refname)' --count=1 --contains=HEAD");
+    let refs = fmt.parse(cmd.execute_output().unwrap().stdout.as_str()).unwrap();
+    assert_eq!(refs, vec![for_each_ref::RefInfo { refname: String::from("refs/heads/feature"), ..Default::default() }]);
+
+    let tracks = for_each_ref::RefFormat::new(&[for_each_ref::RefAtom::UpstreamTrack])
+        .parse("\x1erefs/heads/a\0=ahead 1, behind 3\n\x1erefs/heads/b\0=gone\n\x1erefs/heads/c\0=\n").unwrap();
+    assert_eq!(tracks[0].track, Some(for_each_ref::Tracking { ahead: 1, behind: 3, gone: false }));
+    assert_eq!(tracks[1].track, Some(for_each_ref::Tracking { ahead: 0, behind: 0, gone: true }));
+    assert_eq!(tracks[2].track, Some(for_each_ref::Tracking::default()));
+
+    fs::remove_dir_all(path.as_str()).unwrap();
+}
+
+#[test]
+fn test_cat_file_batch() {
+    let path = gitwrap_test_path();
+    fs::create_dir(path.as_str()).unwrap();
+    init_repo_with_commit(path.as_str());
+    let repo = Repository::open(path.as_str()).unwrap();
+    let dir = Path::new(path.as_str());
+    let files: Vec<(String, Vec<u8>)> = (0..50)
+        .map(|n| (format!("file{}.bin", n), [b"line\n".repeat(n), vec![0u8, 255, b'\n']].concat()))
+        .collect();
+    for (name, content) in &files {
+        fs::write(dir.join(name), content).unwrap();
+    }
+    let cmd = add!(Some(path.as_str()), add::pathspec("."));
+    assert!(cmd.execute().is_ok());
+    let mut cmd = commit!(Some(path.as_str()), commit::message("files"));
+    set_identity(&mut cmd);
+    assert!(cmd.execute().is_ok());
+    let head = repo.head().unwrap().unwrap();
+
+    let mut cmd = repo.cat_file();
+    cmd.option(cat_file::batch());
+    let mut session = cat_file::batch_session(&cmd).unwrap();
+    for (name, content) in &files {
+        match session.get(format!("HEAD:{}", name).as_str()).unwrap() {
+            cat_file::BatchResult::Found(object) => {
+                assert_eq!(object.kind, cat_file::ObjectKind::Blob);
+                assert_eq!(object.size, content.len() as u64);
+                assert_eq!(&object.data, content);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+    assert_eq!(session.get("HEAD:no-such-file").unwrap(), cat_file::BatchResult::Missing(String::from("HEAD:no-such-file")));
+    match session.get(head.as_str()).unwrap() {
+        cat_file::BatchResult::Found(object) => {
+            assert_eq!(object.oid, head);
+            assert_eq!(object.kind, cat_file::ObjectKind::Commit);
+            assert!(String::from_utf8(object.data).unwrap().ends_with("\nfiles\n"));
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    assert!(session.close().is_ok());
+
+    let mut cmd = repo.cat_file();
+    cmd.option(cat_file::batch_check());
+    let mut session = cat_file::batch_session(&cmd).unwrap();
+    match session.get("HEAD^{tree}").unwrap() {
+        cat_file::BatchResult::Found(object) => {
+            assert_eq!(object.kind, cat_file::ObjectKind::Tree);
+            assert!(object.size > 0);
+            assert!(object.data.is_empty());
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    assert!(matches!(session.get("0000000").unwrap(), cat_file::BatchResult::Missing(_)));
+    drop(session);
+
+    let outside = format!("{}_outside", path);
+    fs::create_dir(outside.as_str()).unwrap();
+    let mut cmd = cat_file::cat_file(Some(outside.as_str()));
+    let ceiling = fs::canonicalize(outside.as_str()).unwrap();
+    cmd.env("GIT_CEILING_DIRECTORIES", ceiling.parent().unwrap().to_str().unwrap());
+    cmd.option(cat_file::batch());
+    let result = cat_file::batch_session(&cmd).and_then(|mut s| s.get("HEAD"));
+    assert!(matches!(result, Err(WrapError::NotARepository(_))));
+
+    // the timeout applies while a reply is awaited
+    let mut cmd = WrapCommand::new("sh", None);
+    cmd.option(crate::optionarg::simple("-c"));
+    cmd.option(crate::optionarg::simple("read name; sleep 5"));
+    cmd.timeout(Duration::from_millis(300));
+    let mut session = cat_file::batch_session(&cmd).unwrap();
+    let start = Instant::now();
+    assert!(matches!(session.get("HEAD"), Err(WrapError::Timeout(_))));
+    assert!(start.elapsed() < Duration::from_secs(4));
+
+    fs::remove_dir_all(outside.as_str()).unwrap();
+    fs::remove_dir_all(path.as_str()).unwrap();
+}
+
+#[test]
+fn test_ls_tree() {
+    let path = gitwrap_test_path();
+    fs::create_dir(path.as_str()).unwrap();
+    init_repo_with_commit(path.as_str());
+    let repo = Repository::open(path.as_str()).unwrap();
+    let dir = Path::new(path.as_str());
+    fs::create_dir(dir.join("src")).unwrap();
+    fs::write(dir.join("README"), "readme\n").unwrap();
+    fs::write(dir.join("src").join("tab\there.rs"), "fn main() {}\n").unwrap();
+    let cmd = add!(Some(path.as_str()), add::pathspec("."));
+    assert!(cmd.execute().is_ok());
+    let mut cmd = commit!(Some(path.as_str()), commit::message("files"));
+    set_identity(&mut cmd);
+    assert!(cmd.execute().is_ok());
+    let tagged = repo.head().unwrap().unwrap();
+    // the working tree no longer matches the walked revision
+    fs::remove_file(dir.join("README")).unwrap();
+    fs::write(dir.join("src").join("tab\there.rs"), "changed\n").unwrap();
+
+    let entries: Vec<ls_tree::TreeEntry> = ls_tree::walk_tree(Some(path.as_str()), tagged.as_str()).unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].path, "README");
+    assert_eq!(entries[0].mode, 0o100644);
+    assert_eq!(entries[0].kind, cat_file::ObjectKind::Blob);
+    assert_eq!(entries[0].size, Some(7));
+    assert_eq!(entries[1].path, "src/tab\there.rs");
+    assert_eq!(entries[1].size, Some(13));
+
+    let cmd = ls_tree!(Some(path.as_str()), ls_tree::null(), ls_tree::only_trees(), ls_tree::tree_ish("HEAD"));
+    let entries = ls_tree::parse_ls_tree(cmd.execute_output().unwrap().stdout.as_str()).unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].path, "src");
+    assert_eq!(entries[0].mode, 0o040000);
+    assert_eq!(entries[0].kind, cat_file::ObjectKind::Tree);
+    assert_eq!(entries[0].size, None);
+
+    let mut cmd = repo.ls_tree();
+    cmd.option(ls_tree::tree_ish("no-such-tag"));
+    let mut entries = ls_tree::tree_entries(&cmd).unwrap();
+    assert!(matches!(entries.next(), Some(Err(_))));
+    assert!(entries.next().is_none());
+
+    fs::remove_dir_all(path.as_str()).unwrap();
+}
+
+fn init_repo_with_commit(path: &str) {
+    let cmd = init!(Some(path), init::quiet());
+    assert!(cmd.execute().is_ok());
+
+    let mut cmd = commit!(Some(path),
+        commit::allow_empty(),
+        commit::message("initial commit"));
+    set_identity(&mut cmd);
+    assert!(cmd.execute().is_ok());
+}
+
+fn set_identity(cmd: &mut WrapCommand) {
+    cmd.env("GIT_AUTHOR_NAME", "gitwrap");
+    cmd.env("GIT_AUTHOR_EMAIL", REPO_CONFIG_EMAIL);
+    cmd.env("GIT_COMMITTER_NAME", "gitwrap");
+    cmd.env("GIT_COMMITTER_EMAIL", REPO_CONFIG_EMAIL);
+}
+
 fn cmd_clone(path: &str) -> WrapCommand {
     let mut cmd = clone::clone(None);
     cmd.option(clone::repository("https://github.com/japiber/gitwrap.git"));
@@ -0,0 +1,159 @@
+use std::env;
+use std::fmt::{Display, Formatter};
+use std::sync::{Arc, Mutex, RwLock, Weak};
+use crate::executor::Executor;
+use crate::optionarg;
+use crate::wrap_command::WrapCommand;
+use crate::WrapError;
+
+/// Environment variable naming the git binary to run, when none is set with [set_git_binary]
+pub const GIT_BINARY_ENV: &str = "GITWRAP_GIT";
+const DEFAULT_GIT_BINARY: &str = "git";
+
+// options newer than the oldest git versions still around: (subcommand, option, version introducing it);
+// an empty subcommand stands for a global option
+const MIN_VERSIONS: &[(&str, &str, GitVersion)] = &[
+    ("", "--config-env", GitVersion::new(2, 31, 0)),
+    ("bisect", "--first-parent", GitVersion::new(2, 29, 0)),
+    ("blame", "--ignore-rev", GitVersion::new(2, 23, 0)),
+    ("blame", "--ignore-revs-file", GitVersion::new(2, 23, 0)),
+    ("blame", "--color-lines", GitVersion::new(2, 24, 0)),
+    ("blame", "--color-by-age", GitVersion::new(2, 24, 0)),
+    ("branch", "--show-current", GitVersion::new(2, 22, 0)),
+    ("cherry-pick", "--empty", GitVersion::new(2, 45, 0)),
+    ("clone", "--shallow-since", GitVersion::new(2, 11, 0)),
+    ("clone", "--shallow-exclude", GitVersion::new(2, 11, 0)),
+    ("fetch", "--shallow-since", GitVersion::new(2, 11, 0)),
+    ("fetch", "--shallow-exclude", GitVersion::new(2, 11, 0)),
+    ("for-each-ref", "--omit-empty", GitVersion::new(2, 41, 0)),
+    ("for-each-ref", "--exclude", GitVersion::new(2, 42, 0)),
+    ("ls-files", "--format", GitVersion::new(2, 38, 0)),
+    ("ls-tree", "--object-only", GitVersion::new(2, 36, 0)),
+    ("ls-tree", "--format", GitVersion::new(2, 36, 0)),
+    ("merge", "--autostash", GitVersion::new(2, 27, 0)),
+    ("rebase", "--keep-base", GitVersion::new(2, 24, 0)),
+    ("rebase", "--empty", GitVersion::new(2, 26, 0)),
+    ("rebase", "--rebase-merges", GitVersion::new(2, 18, 0)),
+    ("rebase", "--show-current-patch", GitVersion::new(2, 17, 0)),
+    ("rev-parse", "--show-superproject-working-tree", GitVersion::new(2, 13, 0)),
+    ("revert", "--reference", GitVersion::new(2, 36, 0)),
+    ("stash", "push", GitVersion::new(2, 13, 0)),
+    ("stash", "--staged", GitVersion::new(2, 35, 0)),
+    ("status", "--ahead-behind", GitVersion::new(2, 17, 0)),
+    ("status", "--no-ahead-behind", GitVersion::new(2, 17, 0)),
+    ("submodule", "set-branch", GitVersion::new(2, 22, 0)),
+    ("submodule", "set-url", GitVersion::new(2, 25, 0)),
+    ("worktree", "move", GitVersion::new(2, 17, 0)),
+    ("worktree", "remove", GitVersion::new(2, 17, 0)),
+    ("worktree", "repair", GitVersion::new(2, 30, 0)),
+    ("worktree", "-z", GitVersion::new(2, 36, 0)),
+    ("worktree", "--orphan", GitVersion::new(2, 42, 0)),
+];
+
+static GIT_BINARY: RwLock<Option<String>> = RwLock::new(None);
+// versions found for each binary and executor: a mock or a cassette reports its own versions.
+// The weak reference keeps the address of a dropped executor from being taken by a new one.
+static GIT_VERSIONS: Mutex<Vec<CachedVersion>> = Mutex::new(Vec::new());
+
+// binary, executor queried, version found
+type CachedVersion = (String, Weak<dyn Executor>, GitVersion);
+
+/// A git version, as reported by `git --version`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GitVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl GitVersion {
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self { major, minor, patch }
+    }
+
+    /// Parses the output of `git --version`, e.g. "git version 2.39.5" or "git version 2.45.1.windows.1"
+    pub fn parse(version: &str) -> Option<Self> {
+        let version = version.trim();
+        let version = version.strip_prefix("git version ").unwrap_or(version);
+        let mut numbers = version
+            .split(['.', '-', ' '])
+            .map_while(|n| n.parse::<u32>().ok());
+        Some(Self::new(numbers.next()?, numbers.next()?, numbers.next().unwrap_or(0)))
+    }
+}
+
+impl Display for GitVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Sets the git binary run by the commands created from now on, None restoring the default:
+/// the [GIT_BINARY_ENV] environment variable if set, `git` from the PATH otherwise.
+pub fn set_git_binary(path: Option<&str>) {
+    *GIT_BINARY.write().unwrap() = path.map(String::from);
+}
+
+/// Returns the git binary run by the commands created from now on
+pub fn git_binary() -> String {
+    if let Some(path) = GIT_BINARY.read().unwrap().as_ref() {
+        return path.clone();
+    }
+    env::var(GIT_BINARY_ENV).unwrap_or_else(|_| String::from(DEFAULT_GIT_BINARY))
+}
+
+/// Returns the version of the git binary run by the commands created from now on
+pub fn git_version() -> Result<GitVersion, WrapError> {
+    let mut cmd = WrapCommand::new(git_binary().as_str(), None);
+    cmd.option(optionarg::simple("--version"));
+    query_version(&cmd)
+}
+
+fn query_version(cmd: &WrapCommand) -> Result<GitVersion, WrapError> {
+    let output = cmd.execute_output()?;
+    GitVersion::parse(&output.stdout)
+        .ok_or_else(|| WrapError::FailedExecuteProcess(format!("unexpected git version: {}", output.stdout.trim())))
+}
+
+/// Fails with [WrapError::UnsupportedVersion] if the [globals] options, or the subcommand starting [args]
+/// with the options following it, use an option newer than the git [binary].
+/// The version of each binary is queried once per executor, through [executor].
+pub(crate) fn check_version(binary: &str, globals: &[String], args: &[String], executor: &Arc<dyn Executor>) -> Result<(), WrapError> {
+    let uses = |args: &[String], option: &str| {
+        args.iter().any(|a| a == option || a.strip_prefix(option).is_some_and(|r| r.starts_with('=')))
+    };
+    let required = MIN_VERSIONS.iter().filter(|(subcommand, option, _)| {
+        if subcommand.is_empty() {
+            uses(globals, option)
+        } else {
+            args.first().is_some_and(|a| a == subcommand) && uses(&args[1..], option)
+        }
+    });
+    let Some((_, option, version)) = required.max_by_key(|(_, _, v)| *v) else {
+        return Ok(());
+    };
+
+    let weak = Arc::downgrade(executor);
+    let cached = GIT_VERSIONS.lock().unwrap().iter()
+        .find(|(b, e, _)| b == binary && Weak::ptr_eq(e, &weak))
+        .map(|(_, _, v)| *v);
+    let found = match cached {
+        Some(v) => v,
+        None => {
+            let mut cmd = WrapCommand::new(binary, None);
+            cmd.option(optionarg::simple("--version"));
+            cmd.executor(executor.clone());
+            let v = query_version(&cmd)?;
+            let mut versions = GIT_VERSIONS.lock().unwrap();
+            versions.retain(|(_, e, _)| e.strong_count() > 0);
+            versions.push((String::from(binary), weak, v));
+            v
+        }
+    };
+
+    if found < *version {
+        Err(WrapError::UnsupportedVersion(String::from(*option), *version, found))
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use crate::optionarg;
+use crate::wrap_command::FnOptionArg;
+
+pub const ADD: &str = "add";
+pub const LIST: &str = "list";
+pub const REMOVE: &str = "remove";
+pub const PRUNE: &str = "prune";
+pub const LOCK_WORKTREE: &str = "lock";
+pub const UNLOCK: &str = "unlock";
+pub const MOVE_WORKTREE: &str = "move";
+pub const REPAIR: &str = "repair";
+
+/// Create a worktree at <path> and checkout <commit-ish> into it.
+/// add
+pub fn add() -> FnOptionArg {
+    optionarg::simple(ADD)
+}
+
+/// List details of each worktree.
+/// The main worktree is listed first, followed by each of the linked worktrees.
+/// list
+pub fn list() -> FnOptionArg {
+    optionarg::simple(LIST)
+}
+
+/// Remove a worktree.
+/// Only clean worktrees (no untracked files and no modification in tracked files) can be removed, unless --force is given.
+/// remove
+pub fn remove() -> FnOptionArg {
+    optionarg::simple(REMOVE)
+}
+
+/// Prune worktree information in $GIT_DIR/worktrees.
+/// prune
+pub fn prune() -> FnOptionArg {
+    optionarg::simple(PRUNE)
+}
+
+/// If a worktree is on a portable device or network share which is not always mounted, lock it to prevent its administrative files from being pruned automatically.
+/// lock
+pub fn lock_worktree() -> FnOptionArg {
+    optionarg::simple(LOCK_WORKTREE)
+}
+
+/// Unlock a worktree, allowing it to be pruned, moved or deleted.
+/// unlock
+pub fn unlock() -> FnOptionArg {
+    optionarg::simple(UNLOCK)
+}
+
+/// Move a worktree to a new location.
+/// Note that the main worktree or linked worktrees containing submodules cannot be moved with this command.
+/// move
+pub fn move_worktree() -> FnOptionArg {
+    optionarg::simple(MOVE_WORKTREE)
+}
+
+/// Repair worktree administrative files, if possible, if they have become corrupted or outdated due to external factors.
+/// repair
+pub fn repair() -> FnOptionArg {
+    optionarg::simple(REPAIR)
+}
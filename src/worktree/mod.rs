@@ -0,0 +1,20 @@
+use crate::wrap_command::WrapCommand;
+use crate::git;
+
+mod options;
+pub use options::*;
+
+mod custom;
+pub use custom::*;
+
+mod parse;
+pub use parse::*;
+
+pub const GIT_COMMAND: &str = "worktree";
+
+/// Manage multiple working trees attached to the same repository.
+/// The subcommand is given as the first option, e.g. worktree::add()
+/// [Git doc](https://git-scm.com/docs/git-worktree)
+pub fn worktree(current_dir: Option<&str>) -> WrapCommand {
+    git(GIT_COMMAND, current_dir)
+}
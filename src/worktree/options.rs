@@ -0,0 +1,153 @@
+// Warning!! Code generated automatically: this file must not be edited by hand
+use crate::optionarg;
+use crate::wrap_command::FnOptionArg;
+
+pub const FORCE: &str = "--force";
+pub const NEW_BRANCH: &str = "-b";
+pub const NEW_BRANCH_FORCE: &str = "-B";
+pub const DETACH: &str = "--detach";
+pub const CHECKOUT: &str = "--checkout";
+pub const NO_CHECKOUT: &str = "--no-checkout";
+pub const GUESS_REMOTE: &str = "--guess-remote";
+pub const TRACK: &str = "--track";
+pub const NO_TRACK: &str = "--no-track";
+pub const LOCK: &str = "--lock";
+pub const REASON: &str = "--reason";
+pub const ORPHAN: &str = "--orphan";
+pub const DRY_RUN: &str = "--dry-run";
+pub const PORCELAIN: &str = "--porcelain";
+pub const NULL: &str = "-z";
+pub const QUIET: &str = "--quiet";
+pub const VERBOSE: &str = "--verbose";
+pub const EXPIRE: &str = "--expire";
+
+/// By default, add refuses to create a new worktree when <commit-ish> is a branch name and is already checked out by another worktree, or if <path> is already assigned to some worktree but is missing or locked.
+/// This option overrides these safeguards.
+/// remove refuses to remove an unclean worktree unless --force is used, move and remove refuse to act on a locked worktree unless --force is given twice.
+/// -f, --force
+pub fn force() -> FnOptionArg {
+    optionarg::simple(FORCE)
+}
+
+/// With add, create a new branch named <new-branch> starting at <commit-ish>, and check out <new-branch> into the new worktree.
+/// -b <new-branch>
+pub fn new_branch(new_branch_arg: &str) -> FnOptionArg {
+    optionarg::with_parameter(NEW_BRANCH, new_branch_arg)
+}
+
+/// Like -b, but resets <new-branch> to <commit-ish> if it already exists.
+/// -B <new-branch>
+pub fn new_branch_force(new_branch_arg: &str) -> FnOptionArg {
+    optionarg::with_parameter(NEW_BRANCH_FORCE, new_branch_arg)
+}
+
+/// With add, detach HEAD in the new worktree.
+/// -d, --detach
+pub fn detach() -> FnOptionArg {
+    optionarg::simple(DETACH)
+}
+
+/// By default, add checks out <commit-ish>, however, --no-checkout can be used to suppress checkout in order to make customizations, such as configuring sparse-checkout.
+/// --checkout
+pub fn checkout() -> FnOptionArg {
+    optionarg::simple(CHECKOUT)
+}
+
+/// Do not check out <commit-ish> in the new worktree.
+/// --no-checkout
+pub fn no_checkout() -> FnOptionArg {
+    optionarg::simple(NO_CHECKOUT)
+}
+
+/// With worktree add <path>, without <commit-ish>, instead of creating a new branch from HEAD, if there exists a tracking branch in exactly one remote matching the basename of <path>, base the new branch on the remote-tracking branch, and mark the remote-tracking branch as "upstream" from the new branch.
+/// --guess-remote
+pub fn guess_remote() -> FnOptionArg {
+    optionarg::simple(GUESS_REMOTE)
+}
+
+/// When creating a new branch, if <commit-ish> is a branch, mark it as "upstream" from the new branch.
+/// --track
+pub fn track() -> FnOptionArg {
+    optionarg::simple(TRACK)
+}
+
+/// Do not mark the new branch as "upstream" of <commit-ish>.
+/// --no-track
+pub fn no_track() -> FnOptionArg {
+    optionarg::simple(NO_TRACK)
+}
+
+/// Keep the worktree locked after creation.
+/// --lock
+pub fn lock() -> FnOptionArg {
+    optionarg::simple(LOCK)
+}
+
+/// With lock or with add --lock, an explanation why the worktree is locked.
+/// --reason <string>
+pub fn reason(string_arg: &str) -> FnOptionArg {
+    optionarg::with_parameter(REASON, string_arg)
+}
+
+/// With add, make the new worktree and index empty, associating the worktree with a new unborn branch named <new-branch>.
+/// --orphan
+pub fn orphan() -> FnOptionArg {
+    optionarg::simple(ORPHAN)
+}
+
+/// With prune, do not remove anything; just report what it would remove.
+/// -n, --dry-run
+pub fn dry_run() -> FnOptionArg {
+    optionarg::simple(DRY_RUN)
+}
+
+/// With list, output in an easy-to-parse format for scripts.
+/// --porcelain
+pub fn porcelain() -> FnOptionArg {
+    optionarg::simple(PORCELAIN)
+}
+
+/// Terminate each line with a NUL rather than a newline when --porcelain is specified with list.
+/// -z
+pub fn null() -> FnOptionArg {
+    optionarg::simple(NULL)
+}
+
+/// With add, suppress feedback messages.
+/// -q, --quiet
+pub fn quiet() -> FnOptionArg {
+    optionarg::simple(QUIET)
+}
+
+/// With prune, report all removals.
+/// With list, output additional information about worktrees.
+/// -v, --verbose
+pub fn verbose() -> FnOptionArg {
+    optionarg::simple(VERBOSE)
+}
+
+/// With prune, only expire unused worktrees older than <time>.
+/// With list, annotate missing worktrees as prunable if they are older than <time>.
+/// --expire <time>
+pub fn expire(time_arg: &str) -> FnOptionArg {
+    optionarg::with_parameter(EXPIRE, time_arg)
+}
+
+/// Worktrees can be identified by path, either relative or absolute.
+/// If the last path components in the worktree’s path is unique among worktrees, it can be used to identify a worktree.
+/// <path>
+pub fn path(path: &str) -> FnOptionArg {
+    optionarg::value_parameter(path)
+}
+
+/// Commit to check out in the new worktree (add).
+/// <commit_ish>
+pub fn commit_ish(commit_ish: &str) -> FnOptionArg {
+    optionarg::value_parameter(commit_ish)
+}
+
+/// New location of the worktree (move).
+/// <new_path>
+pub fn new_path(new_path: &str) -> FnOptionArg {
+    optionarg::value_parameter(new_path)
+}
@@ -0,0 +1,55 @@
+use crate::parser::{is_nul_terminated, parse_error, unquote_path};
+use crate::WrapError;
+
+/// A worktree, as listed by `git worktree list --porcelain`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Worktree {
+    pub path: String,
+    /// Commit checked out, None for a bare repository
+    pub head: Option<String>,
+    /// Full name of the branch checked out, e.g. refs/heads/main
+    pub branch: Option<String>,
+    pub bare: bool,
+    pub detached: bool,
+    /// Lock reason, empty if the worktree was locked without one
+    pub locked: Option<String>,
+    /// Reason why the worktree can be pruned
+    pub prunable: Option<String>,
+}
+
+/// Parses the output of `git worktree list --porcelain`, with or without `-z`
+pub fn parse_worktree_list(output: &str) -> Result<Vec<Worktree>, WrapError> {
+    let nul = is_nul_terminated(output);
+    let separator = if nul { '\0' } else { '\n' };
+    let mut worktrees: Vec<Worktree> = Vec::new();
+    let mut current: Option<Worktree> = None;
+
+    for line in output.split(separator) {
+        if line.is_empty() {
+            worktrees.extend(current.take());
+            continue;
+        }
+        let (label, value) = line.split_once(' ').unwrap_or((line, ""));
+        // lock and prune reasons holding newlines are quoted, unless -z is given
+        let reason = || if nul { String::from(value) } else { unquote_path(value) };
+        if label == "worktree" {
+            worktrees.extend(current.take());
+            current = Some(Worktree { path: String::from(value), ..Default::default() });
+            continue;
+        }
+        let worktree = current.as_mut().ok_or_else(|| parse_error("worktree list", line))?;
+        match label {
+            "HEAD" => worktree.head = Some(String::from(value)),
+            "branch" => worktree.branch = Some(String::from(value)),
+            "bare" => worktree.bare = true,
+            "detached" => worktree.detached = true,
+            "locked" => worktree.locked = Some(reason()),
+            "prunable" => worktree.prunable = Some(reason()),
+            // attributes added by newer git versions are ignored
+            _ => {}
+        }
+    }
+    worktrees.extend(current);
+
+    Ok(worktrees)
+}
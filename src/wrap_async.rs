@@ -0,0 +1,106 @@
+use std::future::Future;
+use std::io::Read;
+use std::process::Output;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt};
+use tokio::process::Command;
+use tokio::task;
+use crate::control::{kill_process_group, POLL_INTERVAL};
+use crate::wrap_command::{WrapCommand, WrapOutput};
+use crate::WrapError;
+
+impl WrapCommand {
+    /// Async counterpart of [WrapCommand::execute], running git on the tokio process driver.
+    /// The command line is the same one reported by [WrapCommand::dry_run].
+    /// The returned future does not borrow the command and is Send, so it can be spawned on a runtime.
+    pub fn execute_async(&self) -> impl Future<Output = Result<String, WrapError>> + Send + 'static {
+        let output = self.execute_output_async();
+        async move { output.await.map(|o| o.combined()) }
+    }
+
+    /// Async counterpart of [WrapCommand::execute_output], running git on the tokio process driver.
+    /// Timeout, cancellation and stdin settings are honored as in the blocking execution.
+    /// The returned future does not borrow the command and is Send, so it can be spawned on a runtime.
+    pub fn execute_output_async(&self) -> impl Future<Output = Result<WrapOutput, WrapError>> + Send + 'static {
+        let run = self.run_async();
+        async move {
+            let output = WrapOutput::from(run.await?);
+            if output.success() {
+                Ok(output)
+            } else {
+                Err(WrapError::classify(output))
+            }
+        }
+    }
+
+    // everything read from the command is taken here, synchronously, so the future owns all it needs
+    fn run_async(&self) -> impl Future<Output = Result<Output, WrapError>> + Send + 'static {
+        let version_check = self.version_check();
+        let mut cmd = self.command();
+        let control = self.control(&cmd);
+        // as in the blocking execution, git never reads the host's stdin
+        cmd.stdin(std::process::Stdio::null());
+        let source = self.stdin_source(&mut cmd);
+
+        async move {
+            // the first check of a binary runs `git --version`, which must not block the runtime
+            task::spawn_blocking(version_check).await.map_err(|_| control.failed())??;
+            let source = source.map_err(|_| control.failed())?;
+            let mut cmd = Command::from(cmd);
+            cmd.stdout(std::process::Stdio::piped()).stderr(std::process::Stdio::piped()).kill_on_drop(true);
+            let mut child = cmd.spawn().map_err(|_| control.failed())?;
+
+            let input = match source {
+                Some(reader) => Some(read_source(reader).await.map_err(|_| control.failed())?),
+                None => None,
+            };
+            let stdin = child.stdin.take();
+            let stdout = child.stdout.take();
+            let stderr = child.stderr.take();
+            let feed = async move {
+                if let (Some(mut pipe), Some(data)) = (stdin, input) {
+                    let _ = pipe.write_all(&data).await;
+                }
+            };
+            let wait = async {
+                if control.is_unbounded() {
+                    return child.wait().await.map_err(|_| control.failed());
+                }
+                loop {
+                    if let Some(status) = child.try_wait().map_err(|_| control.failed())? {
+                        return Ok(status);
+                    }
+                    if let Some(e) = control.check() {
+                        // a bounded command leads its own process group
+                        if let Some(id) = child.id() {
+                            kill_process_group(id);
+                        }
+                        let _ = child.kill().await;
+                        control.cleanup();
+                        return Err(e);
+                    }
+                    tokio::time::sleep(POLL_INTERVAL).await;
+                }
+            };
+            let (_, stdout, stderr, status) = tokio::join!(feed, read_pipe(stdout), read_pipe(stderr), wait);
+            let status = status?;
+            Ok(Output { status, stdout, stderr })
+        }
+    }
+}
+
+async fn read_source(mut reader: Box<dyn Read + Send>) -> std::io::Result<Vec<u8>> {
+    task::spawn_blocking(move || {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data).map(|_| data)
+    })
+    .await
+    .map_err(std::io::Error::other)?
+}
+
+async fn read_pipe(pipe: Option<impl AsyncRead + Unpin>) -> Vec<u8> {
+    let mut data = Vec::new();
+    if let Some(mut p) = pipe {
+        let _ = p.read_to_end(&mut data).await;
+    }
+    data
+}
@@ -1,13 +1,113 @@
-use std::process::Command;
-use crate::WrapError;
+use std::cell::RefCell;
+use std::ffi::{OsStr, OsString};
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::process::{Child, Command, Output, Stdio};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use crate::control::Control;
+use crate::executor::{thread_executor, Executor};
+use crate::version::check_version;
+use crate::wrap_stream::{StreamLine, WrapChild};
+use crate::{CancelHandle, WrapError};
 
-pub type FnOptionArg = Box<dyn Fn(&mut Command)>;
+/// A git option, applied to the command line when the command runs.
+/// Options are Send and Sync, so a command can be moved to another thread or task.
+pub type FnOptionArg = Box<dyn Fn(&mut Command) + Send + Sync>;
 
 /// A git command wrapper and manager
 pub struct WrapCommand {
     cmd: String,
+    global_args: Vec<FnOptionArg>,
     args: Vec<FnOptionArg>,
     current_dir: Option<String>,
+    env_clear: bool,
+    envs: Vec<(String, Option<String>)>,
+    stdin: RefCell<Option<Stdin>>,
+    timeout: Option<Duration>,
+    cancel: Option<CancelHandle>,
+    cleanup: Vec<PathBuf>,
+    executor: Arc<dyn Executor>,
+}
+
+/// Data source piped into the standard input of a git command
+pub enum Stdin {
+    /// In-memory data, written in full on every execution
+    Bytes(Vec<u8>),
+    /// Contents of a file, read on every execution
+    File(PathBuf),
+    /// A reader, consumed by the first execution of the command
+    Reader(Box<dyn Read + Send>),
+}
+
+impl From<&str> for Stdin {
+    fn from(s: &str) -> Self {
+        Stdin::Bytes(s.as_bytes().to_vec())
+    }
+}
+
+impl From<Vec<u8>> for Stdin {
+    fn from(b: Vec<u8>) -> Self {
+        Stdin::Bytes(b)
+    }
+}
+
+/// The collected result of a finished git command, keeping stdout and stderr apart
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WrapOutput {
+    /// Standard output, decoded as UTF-8 (invalid sequences are replaced)
+    pub stdout: String,
+    /// Standard error, decoded as UTF-8 (invalid sequences are replaced)
+    pub stderr: String,
+    /// Exit code of the process, None if it was terminated by a signal
+    pub code: Option<i32>,
+    /// Standard output exactly as produced by the process
+    pub raw_stdout: Vec<u8>,
+    /// Standard error exactly as produced by the process
+    pub raw_stderr: Vec<u8>,
+}
+
+impl WrapOutput {
+    /// Builds the output from the exit code and the raw stdout and stderr bytes
+    pub fn new(code: Option<i32>, raw_stdout: Vec<u8>, raw_stderr: Vec<u8>) -> Self {
+        Self {
+            stdout: String::from_utf8_lossy(&raw_stdout).into_owned(),
+            stderr: String::from_utf8_lossy(&raw_stderr).into_owned(),
+            code,
+            raw_stdout,
+            raw_stderr,
+        }
+    }
+
+    /// Returns true if the process exited with code 0
+    pub fn success(&self) -> bool {
+        self.code == Some(0)
+    }
+
+    /// Returns stdout followed by stderr, as provided by [WrapCommand::execute]
+    pub fn combined(&self) -> String {
+        format!("{}{}", self.stdout, self.stderr)
+    }
+}
+
+impl From<Output> for WrapOutput {
+    fn from(o: Output) -> Self {
+        Self::new(o.status.code(), o.stdout, o.stderr)
+    }
+}
+
+/// The full specification of a git command, as provided by [WrapCommand::dry_run_args]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommandSpec {
+    /// The program followed by its arguments
+    pub argv: Vec<OsString>,
+    pub current_dir: Option<PathBuf>,
+    /// True if the inherited environment is cleared
+    pub env_clear: bool,
+    /// Environment overrides in the order they were first set, None standing for a removed variable
+    pub env: Vec<(OsString, Option<OsString>)>,
 }
 
 impl WrapCommand {
@@ -16,55 +116,331 @@ impl WrapCommand {
     pub fn new(cmd: &str, current_dir: Option<&str>) -> Self {
         Self {
             cmd: String::from(cmd),
+            global_args: Vec::new(),
             args: Vec::new(),
             current_dir: current_dir.map(String::from),
+            env_clear: false,
+            envs: Vec::new(),
+            stdin: RefCell::new(None),
+            timeout: None,
+            cancel: None,
+            cleanup: Vec::new(),
+            executor: thread_executor(),
         }
     }
 
+    /// Sets the git binary to run, instead of the one set for all commands (see [crate::set_git_binary])
+    pub fn program(&mut self, path: &str) {
+        self.cmd = String::from(path);
+    }
+
     /// Includes a new git command option
     pub fn option(&mut self, arg: FnOptionArg) {
         self.args.push(arg);
     }
 
+    /// Includes a git global option (see [crate::global]), placed before the git subcommand
+    pub fn global_option(&mut self, arg: FnOptionArg) {
+        self.global_args.push(arg);
+    }
+
+    /// Sets an environment variable for the git process (e.g. GIT_AUTHOR_DATE, GIT_SSH_COMMAND)
+    pub fn env(&mut self, key: &str, value: &str) {
+        self.envs.push((String::from(key), Some(String::from(value))));
+    }
+
+    /// Removes an environment variable from the git process, whether inherited or previously set
+    pub fn env_remove(&mut self, key: &str) {
+        self.envs.push((String::from(key), None));
+    }
+
+    /// Clears the environment inherited by the git process.
+    /// Variables set with [WrapCommand::env] are still passed, whether they were set before or after this call.
+    pub fn env_clear(&mut self) {
+        self.env_clear = true;
+    }
+
+    /// Sets the data piped into the standard input of the git process
+    /// (e.g. for `notes add --stdin` or `commit --file=-`)
+    pub fn stdin(&mut self, input: Stdin) {
+        self.stdin.replace(Some(input));
+    }
+
+    /// Sets the maximum time the git command is allowed to run.
+    /// Past this delay the git process and its process group are killed, and the execution fails with [WrapError::Timeout].
+    pub fn timeout(&mut self, timeout: Duration) {
+        self.timeout = Some(timeout);
+    }
+
+    /// Returns a handle to cancel the execution of the git command from another thread.
+    /// On cancellation the git process and its process group are killed, and the execution fails with [WrapError::Cancelled].
+    pub fn cancel_handle(&mut self) -> CancelHandle {
+        self.cancel.get_or_insert_with(CancelHandle::default).clone()
+    }
+
+    /// Removes [path] if the execution times out or is cancelled, e.g. the target directory of an aborted clone.
+    /// If [path] is an empty directory when the command starts, only what the command created in it is removed;
+    /// any other existing path (a non-empty directory such as a repository, a file) is left untouched.
+    pub fn cleanup_on_abort(&mut self, path: &str) {
+        self.cleanup.push(PathBuf::from(path));
+    }
+
+    /// Sets the executor running the command, instead of the one set for the current thread
+    /// (see [crate::executor::set_thread_executor]).
+    /// Streaming and async executions always run git as a child process.
+    pub fn executor(&mut self, executor: Arc<dyn Executor>) {
+        self.executor = executor;
+    }
+
     /// Executes the git command as a child process, waiting for it to finish and collecting all of its output.
     /// By default, stdout and stderr are captured (and used to provide the result string).
     pub fn execute(&self) -> Result<String, WrapError> {
+        self.execute_output().map(|o| o.combined())
+    }
+
+    /// Executes the git command as a child process, waiting for it to finish and collecting all of its output.
+    /// Unlike [WrapCommand::execute], stdout and stderr are kept apart, so porcelain output can be parsed
+    /// without warnings or progress lines mixed in.
+    /// A non-zero exit code is reported as a [WrapError] holding the same structured output,
+    /// typed after git's failure message (see [WrapError::classify]).
+    pub fn execute_output(&self) -> Result<WrapOutput, WrapError> {
+        self.check_version()?;
+        let output = self.executor.execute(self)?;
+        if output.success() {
+            Ok(output)
+        } else {
+            Err(WrapError::classify(output))
+        }
+    }
+
+    /// Starts the git command as a child process without waiting for it to finish.
+    /// The returned [WrapChild] yields stdout and stderr lines as they arrive,
+    /// with git progress reports parsed into [crate::wrap_stream::Progress] events.
+    pub fn spawn(&self) -> Result<WrapChild, WrapError> {
+        self.check_version()?;
         let mut cmd = self.command();
-        match cmd.output() {
-            Ok(o) => {
-                if o.status.success() {
-                    Ok(format!("{}{}", Self::get_output_string(o.stdout), Self::get_output_string(o.stderr)))
-                } else {
-                    Err(WrapError::ExitStatus(format!("{}{}", Self::get_output_string(o.stdout), Self::get_output_string(o.stderr)), o.status.code().unwrap_or(0)))
-                }
-            }
-            Err(_) => Err(WrapError::FailedExecuteProcess(format!("{:?}", cmd))),
+        let control = self.control(&cmd);
+        cmd.stdin(Stdio::null()).stdout(Stdio::piped()).stderr(Stdio::piped());
+        let source = self.stdin_source(&mut cmd).map_err(|_| control.failed())?;
+        let mut child = cmd.spawn().map_err(|_| control.failed())?;
+        Self::feed_stdin(&mut child, source);
+        Ok(WrapChild::new(child, control))
+    }
+
+    /// Starts the git command as a child process with stdin, stdout and stderr all piped to the caller,
+    /// for commands driven interactively such as `cat-file --batch`
+    pub(crate) fn spawn_piped(&self) -> Result<(Child, Control), WrapError> {
+        self.check_version()?;
+        let mut cmd = self.command();
+        let control = self.control(&cmd);
+        cmd.stdin(Stdio::piped()).stdout(Stdio::piped()).stderr(Stdio::piped());
+        let child = cmd.spawn().map_err(|_| control.failed())?;
+        Ok((child, control))
+    }
+
+    /// Executes the git command, calling [on_line] for every stdout and stderr line as soon as it is produced.
+    /// Useful for long-running commands such as clone or fetch, combined with their `progress()` option.
+    pub fn execute_streaming<F: FnMut(StreamLine)>(&self, mut on_line: F) -> Result<(), WrapError> {
+        let mut child = self.spawn()?;
+        for line in child.by_ref() {
+            on_line(line);
         }
+        child.wait()
     }
 
-    /// Dry-runs the git command returning the full command specification provided
+    /// Dry-runs the git command returning the full command specification provided, quoted for a POSIX shell.
+    /// Environment overrides are shown as a prefix, in the form accepted by `env`.
     pub fn dry_run(&self) -> Result<String, WrapError> {
-        let cmd = self.command();
+        let spec = self.dry_run_args();
         let mut con : Vec<String> = Vec::new();
-        con.push(String::from(cmd.get_program().to_str().unwrap_or("")));
-        for arg in cmd.get_args() {
-            con.push(String::from(arg.to_str().unwrap_or("")));
+        if spec.env_clear || spec.env.iter().any(|(_, v)| v.is_none()) {
+            con.push(String::from("env"));
+            if spec.env_clear {
+                con.push(String::from("-i"));
+            }
+            for (key, _) in spec.env.iter().filter(|(_, v)| v.is_none()) {
+                con.push(String::from("-u"));
+                con.push(shell_quote(key));
+            }
+        }
+        for (key, value) in spec.env.iter().filter_map(|(k, v)| v.as_ref().map(|v| (k, v))) {
+            con.push(format!("{}={}", key.to_string_lossy(), shell_quote(value)));
+        }
+        for arg in &spec.argv {
+            con.push(shell_quote(arg));
         }
         Ok(con.join(" "))
     }
 
-    fn command(&self) -> Command {
+    /// Dry-runs the git command returning the exact argv, working directory and environment overrides it would run with
+    pub fn dry_run_args(&self) -> CommandSpec {
+        let cmd = self.command();
+        let mut argv = vec![cmd.get_program().to_os_string()];
+        argv.extend(cmd.get_args().map(OsStr::to_os_string));
+        let mut env: Vec<(OsString, Option<OsString>)> = Vec::new();
+        for (key, value) in &self.envs {
+            let value = value.as_ref().map(OsString::from);
+            match env.iter_mut().find(|(k, _)| k == key.as_str()) {
+                Some(e) => e.1 = value,
+                None => env.push((OsString::from(key), value)),
+            }
+        }
+        CommandSpec {
+            argv,
+            current_dir: cmd.get_current_dir().map(PathBuf::from),
+            env_clear: self.env_clear,
+            env,
+        }
+    }
+
+    /// Fails early if the command uses an option the installed git does not support yet
+    pub(crate) fn check_version(&self) -> Result<(), WrapError> {
+        self.version_check()()
+    }
+
+    /// The check of [WrapCommand::check_version], detached from the command so it can run on another thread
+    pub(crate) fn version_check(&self) -> impl FnOnce() -> Result<(), WrapError> + Send + 'static {
+        let binary = self.cmd.clone();
+        let mut args: Vec<String> = self.command().get_args().map(|a| a.to_string_lossy().into_owned()).collect();
+        // global options come first, then the subcommand, then its own options
+        let mut globals = Command::new(self.cmd.as_str());
+        for fn_arg in &self.global_args {
+            fn_arg(&mut globals);
+        }
+        let subcommand_args = args.split_off(globals.get_args().count().min(args.len()));
+        let executor = Arc::clone(&self.executor);
+        move || check_version(binary.as_str(), &args, &subcommand_args, &executor)
+    }
+
+    /// Runs the git command as a child process, see [crate::executor::ProcessExecutor]
+    pub(crate) fn execute_process(&self) -> Result<WrapOutput, WrapError> {
+        let mut cmd = self.command();
+        self.run(&mut cmd).map(WrapOutput::from)
+    }
+
+    pub(crate) fn command(&self) -> Command {
         let mut command = Command::new(self.cmd.as_str());
         if let Some(cd) = self.current_dir.as_ref() {
             command.current_dir(cd.as_str());
         }
-        for fn_arg in &self.args {
+        if self.env_clear {
+            command.env_clear();
+        }
+        for (key, value) in &self.envs {
+            match value {
+                Some(v) => command.env(key, v),
+                None => command.env_remove(key),
+            };
+        }
+        #[cfg(unix)]
+        if self.timeout.is_some() || self.cancel.is_some() {
+            // a process group of its own lets an abort kill git along with its helpers (ssh, remote-https...)
+            std::os::unix::process::CommandExt::process_group(&mut command, 0);
+        }
+        for fn_arg in self.global_args.iter().chain(&self.args) {
             fn_arg(&mut command);
         }
         command
     }
 
-    fn get_output_string(out: Vec<u8>) -> String {
-        String::from_utf8(out).unwrap_or_else(|_| String::from(""))
+    pub(crate) fn control(&self, cmd: &Command) -> Control {
+        Control::new(describe(cmd), self.timeout, self.cancel.clone(), &self.cleanup)
+    }
+
+    fn run(&self, cmd: &mut Command) -> Result<Output, WrapError> {
+        let control = self.control(cmd);
+        // git never reads the host's stdin, whichever way it is run
+        cmd.stdin(Stdio::null());
+        let source = self.stdin_source(cmd).map_err(|_| control.failed())?;
+        if source.is_none() && control.is_unbounded() {
+            return cmd.output().map_err(|_| control.failed());
+        }
+
+        let mut child = cmd.stdout(Stdio::piped()).stderr(Stdio::piped()).spawn().map_err(|_| control.failed())?;
+        Self::feed_stdin(&mut child, source);
+        if control.is_unbounded() {
+            return child.wait_with_output().map_err(|_| control.failed());
+        }
+
+        let stdout = child.stdout.take().map(Self::read_pipe);
+        let stderr = child.stderr.take().map(Self::read_pipe);
+        let status = control.wait(&mut child)?;
+        let collect = |reader: Option<thread::JoinHandle<Vec<u8>>>| reader.and_then(|r| r.join().ok()).unwrap_or_default();
+        Ok(Output {
+            status,
+            stdout: collect(stdout),
+            stderr: collect(stderr),
+        })
+    }
+
+    fn read_pipe<R: Read + Send + 'static>(mut pipe: R) -> thread::JoinHandle<Vec<u8>> {
+        thread::spawn(move || {
+            let mut buf = Vec::new();
+            let _ = pipe.read_to_end(&mut buf);
+            buf
+        })
+    }
+
+    /// Configures the stdin of [cmd], returning the data that has to be written to its pipe, if any
+    pub(crate) fn stdin_source(&self, cmd: &mut Command) -> io::Result<Option<Box<dyn Read + Send>>> {
+        let mut stdin = self.stdin.borrow_mut();
+        match stdin.take() {
+            None => Ok(None),
+            Some(Stdin::File(path)) => {
+                cmd.stdin(File::open(&path)?);
+                *stdin = Some(Stdin::File(path));
+                Ok(None)
+            }
+            Some(Stdin::Bytes(bytes)) => {
+                cmd.stdin(Stdio::piped());
+                let source = Box::new(io::Cursor::new(bytes.clone()));
+                *stdin = Some(Stdin::Bytes(bytes));
+                Ok(Some(source))
+            }
+            Some(Stdin::Reader(reader)) => {
+                cmd.stdin(Stdio::piped());
+                Ok(Some(reader))
+            }
+        }
+    }
+
+    fn feed_stdin(child: &mut Child, source: Option<Box<dyn Read + Send>>) {
+        // stdin is written from its own thread so a child filling its output pipes can't deadlock us;
+        // the pipe is closed when the thread ends, signaling EOF to git
+        if let (Some(mut pipe), Some(mut input)) = (child.stdin.take(), source) {
+            thread::spawn(move || {
+                let _ = io::copy(&mut input, &mut pipe);
+            });
+        }
+    }
+}
+
+// the command as reported in errors: environment values are left out, as they may hold credentials
+// such as a token passed in `GIT_CONFIG_VALUE_0`
+fn describe(cmd: &Command) -> String {
+    let mut con: Vec<String> = Vec::new();
+    if let Some(cd) = cmd.get_current_dir() {
+        con.push(format!("cd {:?} &&", cd));
+    }
+    for (key, value) in cmd.get_envs() {
+        match value {
+            Some(_) => con.push(format!("{}=***", key.to_string_lossy())),
+            None => con.push(format!("-u {}", key.to_string_lossy())),
+        }
+    }
+    con.push(format!("{:?}", cmd.get_program()));
+    con.extend(cmd.get_args().map(|a| format!("{:?}", a)));
+    con.join(" ")
+}
+
+/// Quotes [arg] for a POSIX shell, leaving it as is when it only holds characters safe to paste.
+/// Arguments that are not valid UTF-8 are converted lossily.
+fn shell_quote(arg: &OsStr) -> String {
+    let arg = arg.to_string_lossy();
+    let safe = |c: char| c.is_ascii_alphanumeric() || "_-+=/:.,@%".contains(c);
+    if !arg.is_empty() && arg.chars().all(safe) {
+        arg.into_owned()
+    } else {
+        format!("'{}'", arg.replace('\'', "'\\''"))
     }
 }
@@ -0,0 +1,224 @@
+use std::io::{BufRead, BufReader, Read};
+use std::process::Child;
+use std::sync::mpsc::{sync_channel, Receiver, RecvTimeoutError, SyncSender};
+use std::sync::OnceLock;
+use std::thread::{self, JoinHandle};
+use regex::Regex;
+use crate::control::{Control, POLL_INTERVAL};
+use crate::wrap_command::WrapOutput;
+use crate::WrapError;
+
+// Receiving objects:  45% (450/1000), 1.20 MiB | 2.00 MiB/s
+// remote: Enumerating objects: 5
+const EXP_PROGRESS: &str = r#"^(remote: )?([A-Z][\w ]*): +(?:(\d+)% \((\d+)/(\d+)\)|(\d+))(?:, (.+))?$"#;
+const PROGRESS_DONE: &str = ", done.";
+// lines read ahead of the consumer; once they are all pending, git blocks on its full output pipe
+const STREAM_CAPACITY: usize = 1024;
+// longer stderr output without a line break is passed on in pieces of this size
+const MAX_STDERR_LINE: usize = 64 * 1024;
+
+/// A line produced by a running git command
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StreamLine {
+    /// A line written to stdout
+    Stdout(String),
+    /// A line written to stderr, other than a progress report
+    Stderr(String),
+    /// A progress report written to stderr
+    Progress(Progress),
+}
+
+/// A progress report, as printed by git commands run with `--progress`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Progress {
+    /// True if the report comes from the remote side (`remote: ` prefix)
+    pub remote: bool,
+    /// The phase being reported, e.g. "Receiving objects" or "Resolving deltas"
+    pub phase: String,
+    /// Completion percentage, if the total is known
+    pub percent: Option<u8>,
+    /// Number of items processed so far
+    pub current: u64,
+    /// Total number of items, if known
+    pub total: Option<u64>,
+    /// Transfer size and throughput, e.g. "1.20 MiB | 2.00 MiB/s"
+    pub throughput: Option<String>,
+    /// True on the last report of the phase
+    pub done: bool,
+}
+
+/// Parses a git progress line such as `Receiving objects:  45% (450/1000), 1.20 MiB | 2.00 MiB/s`.
+/// Returns None if the line is not a progress report.
+pub fn parse_progress(line: &str) -> Option<Progress> {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    let re = RE.get_or_init(|| Regex::new(EXP_PROGRESS).unwrap());
+
+    let line = line.trim_end();
+    let (line, done) = match line.strip_suffix(PROGRESS_DONE) {
+        Some(l) => (l, true),
+        None => (line, false),
+    };
+    let caps = re.captures(line)?;
+    let number = |i: usize| caps.get(i).and_then(|m| m.as_str().parse::<u64>().ok());
+
+    Some(Progress {
+        remote: caps.get(1).is_some(),
+        phase: String::from(&caps[2]),
+        percent: number(3).map(|p| p.min(100) as u8),
+        current: number(4).or(number(6))?,
+        total: number(5),
+        throughput: caps.get(7).map(|m| String::from(m.as_str())),
+        done,
+    })
+}
+
+/// A running git command, started by [crate::wrap_command::WrapCommand::spawn].
+/// Iterating over it yields stdout and stderr lines as they are produced;
+/// git is held back while too many lines are waiting to be consumed.
+/// Dropping it before [WrapChild::wait] kills the git process.
+pub struct WrapChild {
+    child: Child,
+    lines: Receiver<StreamLine>,
+    readers: Vec<JoinHandle<()>>,
+    stderr: String,
+    control: Control,
+    aborted: Option<WrapError>,
+}
+
+impl WrapChild {
+    pub(crate) fn new(mut child: Child, control: Control) -> Self {
+        let (tx, rx) = sync_channel(STREAM_CAPACITY);
+        let mut readers = Vec::new();
+        if let Some(stdout) = child.stdout.take() {
+            let tx = tx.clone();
+            readers.push(thread::spawn(move || read_stdout(stdout, tx)));
+        }
+        if let Some(stderr) = child.stderr.take() {
+            readers.push(thread::spawn(move || read_stderr(stderr, tx)));
+        }
+        Self {
+            child,
+            lines: rx,
+            readers,
+            stderr: String::new(),
+            control,
+            aborted: None,
+        }
+    }
+
+    /// OS-assigned process identifier of the git process
+    pub fn id(&self) -> u32 {
+        self.child.id()
+    }
+
+    /// Waits for the git command to exit, discarding any output not consumed yet.
+    /// A non-zero exit code is reported as a [WrapError] holding the stderr lines of the command (see [WrapError::classify]).
+    pub fn wait(mut self) -> Result<(), WrapError> {
+        for _ in self.by_ref() {}
+        if let Some(e) = self.aborted.take() {
+            return Err(e);
+        }
+        for reader in self.readers.drain(..) {
+            let _ = reader.join();
+        }
+        let status = self.control.wait(&mut self.child)?;
+        if status.success() {
+            Ok(())
+        } else {
+            Err(WrapError::classify(WrapOutput::new(status.code(), Vec::new(), std::mem::take(&mut self.stderr).into_bytes())))
+        }
+    }
+
+    fn recv(&mut self) -> Option<StreamLine> {
+        if self.aborted.is_some() {
+            return None;
+        }
+        if self.control.is_unbounded() {
+            return self.lines.recv().ok();
+        }
+        loop {
+            // checked on every pass: a command that keeps writing must still time out
+            if let Some(e) = self.control.check() {
+                self.control.abort(&mut self.child);
+                self.aborted = Some(e);
+                return None;
+            }
+            match self.lines.recv_timeout(POLL_INTERVAL) {
+                Ok(line) => return Some(line),
+                Err(RecvTimeoutError::Disconnected) => return None,
+                Err(RecvTimeoutError::Timeout) => {}
+            }
+        }
+    }
+}
+
+impl Drop for WrapChild {
+    // a child dropped before wait() completes, e.g. when the caller stops iterating early, is killed and reaped.
+    // Its reader threads end once the receiving side of the channel is dropped.
+    fn drop(&mut self) {
+        if let Ok(None) = self.child.try_wait() {
+            self.control.kill(&mut self.child);
+        }
+    }
+}
+
+impl Iterator for WrapChild {
+    type Item = StreamLine;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = self.recv()?;
+        if let StreamLine::Stderr(l) = &line {
+            self.stderr.push_str(l);
+            self.stderr.push('\n');
+        }
+        Some(line)
+    }
+}
+
+fn read_stdout(stdout: impl Read, tx: SyncSender<StreamLine>) {
+    read_lines(stdout, b"\n", usize::MAX, |line| tx.send(StreamLine::Stdout(line)).is_ok());
+}
+
+fn read_stderr(stderr: impl Read, tx: SyncSender<StreamLine>) {
+    // progress reports are redrawn in place using carriage returns, messages end with newlines
+    read_lines(stderr, b"\r\n", MAX_STDERR_LINE, |line| {
+        if line.is_empty() {
+            return true;
+        }
+        let line = match parse_progress(&line) {
+            Some(p) => StreamLine::Progress(p),
+            None => StreamLine::Stderr(line),
+        };
+        tx.send(line).is_ok()
+    });
+}
+
+// sends each line as soon as one of [delimiters] is read, and a line reaching [max_len] bytes in pieces
+fn read_lines(input: impl Read, delimiters: &[u8], max_len: usize, mut send: impl FnMut(String) -> bool) {
+    let mut reader = BufReader::new(input);
+    let mut line = Vec::new();
+    loop {
+        let (read, complete) = match reader.fill_buf() {
+            Ok([]) | Err(_) => break,
+            Ok(buf) => {
+                let end = buf.iter().position(|b| delimiters.contains(b));
+                let take = end.unwrap_or(buf.len()).min(max_len - line.len());
+                line.extend_from_slice(&buf[..take]);
+                if end == Some(take) { (take + 1, true) } else { (take, line.len() == max_len) }
+            }
+        };
+        reader.consume(read);
+        if complete {
+            if line.last() == Some(&b'\r') {
+                line.pop();
+            }
+            if !send(String::from_utf8_lossy(&line).into_owned()) {
+                return;
+            }
+            line.clear();
+        }
+    }
+    if !line.is_empty() {
+        send(String::from_utf8_lossy(&line).into_owned());
+    }
+}